@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use clap::{Subcommand, ValueEnum};
 use rrelayer_core::{
     authentication::types::JwtRole,
-    common_types::{EvmAddress, PagingQuery},
+    common_types::{CursorPagingContext, EvmAddress},
 };
 use rrelayer_sdk::SDK;
 
@@ -14,8 +14,16 @@ use crate::{
 
 #[derive(Subcommand)]
 pub enum UserCommand {
-    /// List all users
-    List,
+    /// List users, page by page
+    List {
+        /// Opaque cursor returned by the previous page; omit for the first page
+        #[clap(long)]
+        cursor: Option<String>,
+
+        /// Maximum number of users to return in this page
+        #[clap(long, default_value_t = 100)]
+        limit: u32,
+    },
     /// Edit user role
     Edit {
         /// The address to edit
@@ -89,29 +97,39 @@ pub async fn handle_user(
     sdk: &mut SDK,
 ) -> Result<(), UserError> {
     match command {
-        UserCommand::List => handle_list(project_path, sdk).await,
+        UserCommand::List { cursor, limit } => {
+            handle_list(cursor.clone(), *limit, project_path, sdk).await
+        }
         UserCommand::Edit { address, role } => handle_edit(address, role, project_path, sdk).await,
         UserCommand::Add { address, role } => handle_add(address, role, project_path, sdk).await,
         UserCommand::Delete { address } => handle_delete(address, project_path, sdk).await,
     }
 }
 
-/// Lists all users and their roles in a formatted table.
+/// Lists one page of users and their roles in a formatted table.
 ///
-/// Authenticates the user and retrieves all users, then displays them
-/// in a table format with their addresses and assigned roles.
+/// Authenticates the user and retrieves a single page of users starting from `cursor` (or the
+/// first page when `cursor` is `None`), then displays them in a table with a "next page" hint
+/// when more users remain.
 ///
 /// # Arguments
+/// * `cursor` - Opaque cursor returned by the previous page, or `None` for the first page
+/// * `limit` - Maximum number of users to fetch for this page
 /// * `project_path` - The project location containing configuration and keystores
 /// * `sdk` - Mutable reference to the SDK for making API calls
 ///
 /// # Returns
 /// * `Ok(())` - Users listed successfully
 /// * `Err(UserError)` - Authentication failed or user retrieval failed
-async fn handle_list(project_path: &ProjectLocation, sdk: &mut SDK) -> Result<(), UserError> {
+async fn handle_list(
+    cursor: Option<String>,
+    limit: u32,
+    project_path: &ProjectLocation,
+    sdk: &mut SDK,
+) -> Result<(), UserError> {
     handle_authenticate(sdk, "account1", project_path).await?;
 
-    log_users(sdk).await?;
+    log_users(cursor, limit, sdk).await?;
 
     Ok(())
 }
@@ -204,40 +222,43 @@ async fn handle_edit(
     Ok(())
 }
 
-/// Retrieves and displays users in a formatted table.
+/// Retrieves and displays one page of users in a formatted table.
 ///
-/// Fetches all users from the API and displays them in a table format
-/// with columns for address and role. Includes pagination context
-/// but currently retrieves all users.
+/// Fetches a single page of users from the API, walked via keyset (cursor) pagination rather
+/// than offset, and displays them in a table format with columns for address and role. The
+/// footer hints at the `--cursor` to pass for the next page when one is available.
 ///
 /// # Arguments
+/// * `cursor` - Opaque cursor returned by the previous page, or `None` for the first page
+/// * `limit` - Maximum number of users to fetch for this page
 /// * `sdk` - Mutable reference to the SDK for making API calls
 ///
 /// # Returns
 /// * `Ok(())` - Users displayed successfully
 /// * `Err(UserError)` - Failed to fetch users from API
-async fn log_users(sdk: &mut SDK) -> Result<(), UserError> {
-    let users = sdk
+async fn log_users(cursor: Option<String>, limit: u32, sdk: &mut SDK) -> Result<(), UserError> {
+    let result = sdk
         .user
-        .get(&PagingQuery {
-            // don't handle paging just yet as probably not required
-            limit: 1000,
-            offset: 0,
-        })
-        .await?
-        .items;
+        .get(&CursorPagingContext::new(cursor, limit))
+        .await?;
 
     let mut rows = Vec::new();
-    for user in users.iter() {
+    for user in result.items.iter() {
         rows.push(vec![user.address.hex(), user.role.to_string()]);
     }
 
     let headers = vec!["Address", "Role"];
 
-    let title = format!("{} Users:", users.len());
-    let footer = "Roles can be admin, manager, integrator and readonly";
+    let title = format!("{} Users:", result.items.len());
+    let footer = match &result.next_cursor {
+        Some(next_cursor) => format!(
+            "Roles can be admin, manager, integrator and readonly. Next page: --cursor {}",
+            next_cursor
+        ),
+        None => "Roles can be admin, manager, integrator and readonly".to_string(),
+    };
 
-    print_table(headers, rows, Some(&title), Some(footer));
+    print_table(headers, rows, Some(&title), Some(&footer));
 
     Ok(())
 }
@@ -13,7 +13,7 @@ use crate::{
     provider::EvmProvider,
     rate_limiting::RateLimiter,
     shared::cache::Cache,
-    transaction::queue_system::TransactionsQueues,
+    transaction::queue_system::{NodeId, TransactionsQueues},
     webhooks::WebhookManager,
     yaml::RateLimitConfig,
     SafeProxyManager,
@@ -65,6 +65,9 @@ pub struct AppState {
     pub api_keys: Arc<Vec<(ChainId, Vec<ApiKey>)>>,
     /// Network configurations to check feature availability
     pub network_configs: Arc<Vec<NetworkSetupConfig>>,
+    /// This process's identity in the multi-instance relayer lease scheme, used to stamp newly
+    /// created relayers as owned by this node so they participate in lease fencing from the start.
+    pub node_id: NodeId,
 }
 
 pub enum NetworkValidateAction {
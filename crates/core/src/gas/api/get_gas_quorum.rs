@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, gas::QuorumReport, network::ChainId};
+
+/// Retrieves the most recent quorum reconciliation summary for a specific chain via HTTP API.
+///
+/// Only populated for chains whose configured gas estimator is a `CompositeGasFeeEstimator`
+/// running in `CompositeMode::Quorum`; every other chain/mode returns 404.
+pub async fn get_gas_quorum(
+    State(state): State<Arc<AppState>>,
+    Path(chain_id): Path<ChainId>,
+) -> Result<Json<QuorumReport>, HttpError> {
+    let quorum_report = state
+        .gas_oracle_cache
+        .lock()
+        .await
+        .get_quorum_report(&chain_id)
+        .await
+        .ok_or(not_found("quorum report not found".to_string()))?;
+
+    Ok(Json(quorum_report))
+}
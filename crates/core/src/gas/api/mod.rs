@@ -5,6 +5,7 @@ use axum::{routing::get, Router};
 use crate::app_state::AppState;
 
 pub mod get_gas_price;
+pub mod get_gas_quorum;
 
 /// Creates and configures the gas-related HTTP routes.
 ///
@@ -14,5 +15,7 @@ pub mod get_gas_price;
 /// # Returns
 /// * `Router<Arc<AppState>>` - Configured router with gas price endpoints
 pub fn create_gas_routes() -> Router<Arc<AppState>> {
-    Router::new().route("/price/:chain_id", get(get_gas_price::get_gas_price))
+    Router::new()
+        .route("/price/:chain_id", get(get_gas_price::get_gas_price))
+        .route("/price/:chain_id/quorum", get(get_gas_quorum::get_gas_quorum))
 }
@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use super::{
-    blocknative::BlockNativeGasFeeEstimator, etherscan::EtherscanGasFeeEstimator,
+    blocknative::BlockNativeGasFeeEstimator,
+    cached::CachedGasFeeEstimator,
+    composite::{CompositeGasFeeEstimator, CompositeModeConfig, QuorumReport},
+    etherscan::EtherscanGasFeeEstimator,
+    fee_history::FeeHistoryGasFeeEstimator,
     infura::InfuraGasFeeEstimator,
 };
 use crate::gas::fee_estimator::fallback::FallbackGasFeeEstimator;
@@ -20,10 +24,12 @@ use crate::{
         types::{GasPrice, GasProvider, MaxFee, MaxPriorityFee},
     },
     network::ChainId,
-    provider::RetryClientError,
+    provider::{detect_node_client, RetryClientError},
     NetworkSetupConfig, SetupConfig,
 };
 
+const LEGACY_WAIT_BUCKETS: [(i64, i64); 4] = [(120, 300), (30, 120), (15, 60), (5, 30)];
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GasPriceResult {
     #[serde(rename = "maxPriorityFee")]
@@ -37,6 +43,11 @@ pub struct GasPriceResult {
 
     #[serde(rename = "maxWaitTimeEstimate")]
     pub max_wait_time_estimate: Option<i64>,
+
+    /// L1 data-fee contribution in wei, for OP-Stack L2s where this is read from the
+    /// `GasPriceOracle` predeploy. `None` on chains where L1 data fees don't apply.
+    #[serde(rename = "l1DataFee", skip_serializing_if = "Option::is_none", default)]
+    pub l1_data_fee: Option<u128>,
 }
 
 impl GasPriceResult {
@@ -47,6 +58,52 @@ impl GasPriceResult {
     pub fn legacy_gas_price(&self) -> GasPrice {
         GasPrice::new(self.max_fee.into_u128() + self.max_priority_fee.into_u128())
     }
+
+    /// Total cost in wei for this speed tier, including the L2 execution fee plus the L1
+    /// data fee when one has been computed for the chain. Balance checks and total-cost
+    /// estimates on L2 relayers should use this instead of the execution fee alone, which
+    /// undercounts whenever the L1 portion dominates.
+    pub fn total_fee_with_l1_data_fee(&self, gas_limit: u128) -> u128 {
+        let execution_fee = (self.max_fee.into_u128() + self.max_priority_fee.into_u128())
+            .saturating_mul(gas_limit);
+        execution_fee.saturating_add(self.l1_data_fee.unwrap_or(0))
+    }
+
+    /// Builds a `GasPriceResult` for a chain that does not support EIP-1559.
+    ///
+    /// There is only a single `gasPrice` on legacy chains, so it is stored in `max_fee`
+    /// with `max_priority_fee` left at zero; `legacy_gas_price()` on the result therefore
+    /// still returns the original gas price unchanged.
+    pub fn legacy(
+        gas_price: GasPrice,
+        min_wait_time_estimate: Option<i64>,
+        max_wait_time_estimate: Option<i64>,
+    ) -> Self {
+        Self {
+            max_priority_fee: MaxPriorityFee::new(0),
+            max_fee: MaxFee::new(gas_price.into_u128()),
+            min_wait_time_estimate,
+            max_wait_time_estimate,
+            l1_data_fee: None,
+        }
+    }
+}
+
+impl GasEstimatorResult {
+    /// Builds a `GasEstimatorResult` for a non-EIP-1559 chain from a single legacy `gasPrice`,
+    /// scaling it per speed tier the same way the fallback estimator scales EIP-1559 fees.
+    pub fn legacy_from_gas_price(gas_price: u128) -> Self {
+        let tier = |multiplier: u128, (min_wait, max_wait): (i64, i64)| {
+            GasPriceResult::legacy(GasPrice::new((gas_price * multiplier) / 100), Some(min_wait), Some(max_wait))
+        };
+
+        GasEstimatorResult {
+            slow: tier(80, LEGACY_WAIT_BUCKETS[0]),
+            medium: tier(100, LEGACY_WAIT_BUCKETS[1]),
+            fast: tier(130, LEGACY_WAIT_BUCKETS[2]),
+            super_fast: tier(180, LEGACY_WAIT_BUCKETS[3]),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -107,6 +164,27 @@ pub trait BaseGasFeeEstimator {
         chain_id: &ChainId,
     ) -> Result<GasEstimatorResult, GasEstimatorError>;
     fn is_chain_supported(&self, chain_id: &ChainId) -> bool;
+
+    /// The most recent `CompositeMode::Quorum` reconciliation summary for `chain_id`, if this
+    /// estimator is (or wraps) a `CompositeGasFeeEstimator` running in quorum mode. `None` for
+    /// every other estimator/mode, since there's nothing to report.
+    fn last_quorum_report(&self, _chain_id: &ChainId) -> Option<QuorumReport> {
+        None
+    }
+}
+
+/// Wraps `estimator` in a [`CachedGasFeeEstimator`] when `ttl_seconds` is configured, so callers
+/// of `get_gas_estimator` never have to think about caching themselves.
+fn wrap_with_cache(
+    estimator: Arc<dyn BaseGasFeeEstimator + Send + Sync>,
+    ttl_seconds: Option<u64>,
+) -> Arc<dyn BaseGasFeeEstimator + Send + Sync> {
+    match ttl_seconds {
+        Some(ttl_seconds) => {
+            Arc::new(CachedGasFeeEstimator::new(estimator, std::time::Duration::from_secs(ttl_seconds)))
+        }
+        None => estimator,
+    }
 }
 
 /// Creates and returns the appropriate gas fee estimator based on configuration.
@@ -120,30 +198,129 @@ pub async fn get_gas_estimator(
             match network_gas_provider {
                 GasProvider::BLOCKNATIVE => {
                     if let Some(setup) = &setup_gas_providers.blocknative {
-                        return Ok(Arc::new(BlockNativeGasFeeEstimator::new(setup.clone())?));
+                        return Ok(wrap_with_cache(
+                            Arc::new(BlockNativeGasFeeEstimator::new(setup.clone())?),
+                            setup_gas_providers.cache_ttl_seconds,
+                        ));
                     }
                 }
                 GasProvider::ETHERSCAN => {
                     if let Some(setup) = &setup_gas_providers.etherscan {
-                        return Ok(Arc::new(EtherscanGasFeeEstimator::new(setup.clone())?));
+                        return Ok(wrap_with_cache(
+                            Arc::new(EtherscanGasFeeEstimator::new(setup.clone())?),
+                            setup_gas_providers.cache_ttl_seconds,
+                        ));
                     }
                 }
                 GasProvider::TENDERLY => {
                     if let Some(setup) = &setup_gas_providers.tenderly {
-                        return Ok(Arc::new(TenderlyGasFeeEstimator::new(&setup.api_key)));
+                        return Ok(wrap_with_cache(
+                            Arc::new(TenderlyGasFeeEstimator::new(&setup.api_key)),
+                            setup_gas_providers.cache_ttl_seconds,
+                        ));
                     }
                 }
                 GasProvider::INFURA => {
                     if let Some(setup) = &setup_gas_providers.infura {
-                        return Ok(Arc::new(InfuraGasFeeEstimator::new(
-                            &setup.api_key,
-                            &setup.secret,
-                        )));
+                        return Ok(wrap_with_cache(
+                            Arc::new(InfuraGasFeeEstimator::new(
+                                &setup.api_key,
+                                &setup.secret,
+                                setup.tier_multipliers.clone(),
+                            )),
+                            setup_gas_providers.cache_ttl_seconds,
+                        ));
                     }
                 }
                 GasProvider::CUSTOM => {
                     if let Some(setup) = &setup_gas_providers.custom {
-                        return Ok(Arc::new(setup.to_owned()));
+                        return Ok(wrap_with_cache(
+                            Arc::new(setup.to_owned()),
+                            setup_gas_providers.cache_ttl_seconds,
+                        ));
+                    }
+                }
+                GasProvider::FEE_HISTORY => {
+                    if let Some(setup) = &setup_gas_providers.fee_history {
+                        if setup.enabled {
+                            let provider = create_retry_client(&provider_urls[0]).await?;
+                            return Ok(wrap_with_cache(
+                                Arc::new(FeeHistoryGasFeeEstimator::new_with_min_priority_fee(
+                                    provider.clone(),
+                                    network.supports_eip1559,
+                                    setup.min_priority_fee_wei,
+                                )),
+                                setup_gas_providers.cache_ttl_seconds,
+                            ));
+                        }
+                    }
+                }
+                GasProvider::COMPOSITE => {
+                    if let Some(composite_setup) = &setup_gas_providers.composite {
+                        let mut estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>> =
+                            Vec::new();
+
+                        if let Some(setup) = &setup_gas_providers.blocknative {
+                            estimators.push(Arc::new(BlockNativeGasFeeEstimator::new(setup.clone())?));
+                        }
+                        if let Some(setup) = &setup_gas_providers.etherscan {
+                            estimators.push(Arc::new(EtherscanGasFeeEstimator::new(setup.clone())?));
+                        }
+                        if let Some(setup) = &setup_gas_providers.tenderly {
+                            estimators.push(Arc::new(TenderlyGasFeeEstimator::new(&setup.api_key)));
+                        }
+                        if let Some(setup) = &setup_gas_providers.infura {
+                            estimators.push(Arc::new(InfuraGasFeeEstimator::new(
+                                &setup.api_key,
+                                &setup.secret,
+                                setup.tier_multipliers.clone(),
+                            )));
+                        }
+                        if let Some(setup) = &setup_gas_providers.custom {
+                            estimators.push(Arc::new(setup.to_owned()));
+                        }
+                        if let Some(setup) = &setup_gas_providers.fee_history {
+                            if setup.enabled {
+                                let provider = create_retry_client(&provider_urls[0]).await?;
+                                estimators.push(Arc::new(
+                                    FeeHistoryGasFeeEstimator::new_with_min_priority_fee(
+                                        provider.clone(),
+                                        network.supports_eip1559,
+                                        setup.min_priority_fee_wei,
+                                    ),
+                                ));
+                            }
+                        }
+
+                        if !estimators.is_empty() {
+                            let composite = match composite_setup.mode {
+                                CompositeModeConfig::Priority => {
+                                    CompositeGasFeeEstimator::new(estimators)
+                                }
+                                CompositeModeConfig::Median => CompositeGasFeeEstimator::new_with_mode(
+                                    estimators,
+                                    composite_setup.mode.into(),
+                                ),
+                                CompositeModeConfig::WeightedMedian => {
+                                    CompositeGasFeeEstimator::new_with_weighted_median(
+                                        estimators,
+                                        composite_setup.weights.clone().unwrap_or_default(),
+                                    )
+                                }
+                                CompositeModeConfig::Quorum => {
+                                    CompositeGasFeeEstimator::new_with_quorum(
+                                        estimators,
+                                        composite_setup.quorum_min_responses,
+                                        composite_setup.quorum_max_deviation_bps,
+                                    )
+                                }
+                            };
+
+                            return Ok(wrap_with_cache(
+                                Arc::new(composite),
+                                setup_gas_providers.cache_ttl_seconds,
+                            ));
+                        }
                     }
                 }
             }
@@ -151,5 +328,12 @@ pub async fn get_gas_estimator(
     }
 
     let provider = create_retry_client(&provider_urls[0]).await?;
-    Ok(Arc::new(FallbackGasFeeEstimator::new(provider.clone())))
+    let node_client = detect_node_client(&provider).await;
+    let fallback = Arc::new(FallbackGasFeeEstimator::new(
+        provider.clone(),
+        network.supports_eip1559,
+        node_client,
+    ));
+    let ttl_seconds = setup_config.gas_providers.as_ref().and_then(|p| p.cache_ttl_seconds);
+    Ok(wrap_with_cache(fallback, ttl_seconds))
 }
@@ -1,17 +1,21 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use super::base::{BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult, GasPriceResult};
-
-const GWEI_TO_WEI: u128 = 1_000_000_000;
+use super::base::{
+    parse_formatted_gas_to_u128, BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult,
+    GasPriceResult,
+};
 use crate::{
     gas::types::{MaxFee, MaxPriorityFee},
     network::ChainId,
 };
 
+/// BlockNative also exposes a limited keyless tier, so `api_key` is optional here; when it's
+/// absent `is_chain_supported` reports false and the aggregator skips this estimator instead of
+/// every request failing against the authenticated endpoint.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BlockNativeGasProviderSetupConfig {
-    pub api_key: String,
+    pub api_key: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -23,95 +27,55 @@ struct BlockNativeGasEstimateResult {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct BlockNativeEstimatedPrice {
     confidence: u8,
-    price: u64,
     #[serde(rename = "maxPriorityFeePerGas")]
-    max_priority_fee_per_gas: u64,
+    max_priority_fee_per_gas: String,
     #[serde(rename = "maxFeePerGas")]
-    max_fee_per_gas: u64,
+    max_fee_per_gas: String,
 }
 
 impl BlockNativeGasEstimateResult {
     fn get_estimate_by_confidence(&self, confidence: u8) -> Option<&BlockNativeEstimatedPrice> {
-        self.estimated_prices.iter().find(|price| price.confidence == confidence)
+        self.estimated_prices
+            .iter()
+            .find(|price| price.confidence == confidence)
     }
 
-    pub fn to_base_result(&self) -> Result<GasEstimatorResult, GasEstimatorError> {
-        // BlockNative typically provides confidence levels: 70, 80, 90, 95, 99
-        let slow = self
-            .get_estimate_by_confidence(70)
-            .or_else(|| self.estimated_prices.first())
-            .ok_or_else(|| {
-                GasEstimatorError::CustomError("No gas estimates available".to_string())
-            })?;
-
-        let medium = self
-            .get_estimate_by_confidence(80)
-            .or_else(|| self.get_estimate_by_confidence(70))
-            .or_else(|| self.estimated_prices.first())
-            .ok_or_else(|| {
-                GasEstimatorError::CustomError("No gas estimates available".to_string())
-            })?;
-
-        let fast = self
-            .get_estimate_by_confidence(90)
-            .or_else(|| self.get_estimate_by_confidence(95))
-            .or_else(|| self.estimated_prices.last())
-            .ok_or_else(|| {
-                GasEstimatorError::CustomError("No gas estimates available".to_string())
-            })?;
-
-        let super_fast = self
-            .get_estimate_by_confidence(95)
-            .or_else(|| self.get_estimate_by_confidence(99))
+    /// Maps BlockNative's confidence-tagged buckets (typically 70/80/90/95/99) onto our four
+    /// speed tiers, falling back to the nearest available bucket when the exact confidence
+    /// level isn't present in the response.
+    fn gas_price_result(
+        &self,
+        preferred: u8,
+        fallback: u8,
+    ) -> Result<GasPriceResult, GasEstimatorError> {
+        let estimate = self
+            .get_estimate_by_confidence(preferred)
+            .or_else(|| self.get_estimate_by_confidence(fallback))
             .or_else(|| self.estimated_prices.last())
             .ok_or_else(|| {
                 GasEstimatorError::CustomError("No gas estimates available".to_string())
             })?;
 
-        let slow_result = GasPriceResult {
-            max_priority_fee: MaxPriorityFee::new(
-                slow.max_priority_fee_per_gas as u128 * GWEI_TO_WEI,
-            ),
-            max_fee: MaxFee::new(slow.max_fee_per_gas as u128 * GWEI_TO_WEI),
-            min_wait_time_estimate: None,
-            max_wait_time_estimate: None,
-        };
-
-        let medium_result = GasPriceResult {
-            max_priority_fee: MaxPriorityFee::new(
-                medium.max_priority_fee_per_gas as u128 * GWEI_TO_WEI,
-            ),
-            max_fee: MaxFee::new(medium.max_fee_per_gas as u128 * GWEI_TO_WEI),
-            min_wait_time_estimate: None,
-            max_wait_time_estimate: None,
-        };
+        let max_priority_fee = parse_formatted_gas_to_u128(&estimate.max_priority_fee_per_gas)
+            .map_err(GasEstimatorError::UnitsError)?;
+        let max_fee = parse_formatted_gas_to_u128(&estimate.max_fee_per_gas)
+            .map_err(GasEstimatorError::UnitsError)?;
 
-        let fast_result = GasPriceResult {
-            max_priority_fee: MaxPriorityFee::new(
-                fast.max_priority_fee_per_gas as u128 * GWEI_TO_WEI,
-            ),
-            max_fee: MaxFee::new(fast.max_fee_per_gas as u128 * GWEI_TO_WEI),
+        Ok(GasPriceResult {
+            max_priority_fee: MaxPriorityFee::new(max_priority_fee),
+            max_fee: MaxFee::new(max_fee),
             min_wait_time_estimate: None,
             max_wait_time_estimate: None,
-        };
-
-        // For super fast, add 20% buffer to the highest confidence estimate
-        let super_fast_priority =
-            (super_fast.max_priority_fee_per_gas as u128 * 120 / 100) * GWEI_TO_WEI;
-        let super_fast_max = (super_fast.max_fee_per_gas as u128 * 120 / 100) * GWEI_TO_WEI;
-
-        let super_fast_result = GasPriceResult {
-            max_priority_fee: MaxPriorityFee::new(super_fast_priority),
-            max_fee: MaxFee::new(super_fast_max),
-            min_wait_time_estimate: None,
-            max_wait_time_estimate: None,
-        };
+            l1_data_fee: None,
+        })
+    }
 
+    pub fn to_base_result(&self) -> Result<GasEstimatorResult, GasEstimatorError> {
         Ok(GasEstimatorResult {
-            slow: slow_result,
-            medium: medium_result,
-            fast: fast_result,
-            super_fast: super_fast_result,
+            slow: self.gas_price_result(70, 80)?,
+            medium: self.gas_price_result(90, 80)?,
+            fast: self.gas_price_result(95, 90)?,
+            super_fast: self.gas_price_result(99, 95)?,
         })
     }
 }
@@ -134,16 +98,22 @@ impl BaseGasFeeEstimator for BlockNativeGasFeeEstimator {
         &self,
         chain_id: &ChainId,
     ) -> Result<GasEstimatorResult, GasEstimatorError> {
-        let url =
-            format!("https://api.blocknative.com/gasprices/blockprices?chainid={}", chain_id.u64());
+        let api_key = self.config.api_key.as_ref().ok_or_else(|| {
+            GasEstimatorError::CustomError("BlockNative api_key not configured".to_string())
+        })?;
+
+        let url = format!(
+            "https://api.blocknative.com/gasprices/blockprices?chainid={}",
+            chain_id.u64()
+        );
 
         let response = self
             .client
             .get(&url)
-            .header("Authorization", &self.config.api_key)
+            .header("Authorization", api_key)
             .send()
             .await
-            .map_err(|e| GasEstimatorError::ReqwestError(e))?;
+            .map_err(GasEstimatorError::ReqwestError)?;
 
         if !response.status().is_success() {
             return Err(GasEstimatorError::CustomError(format!(
@@ -152,15 +122,18 @@ impl BaseGasFeeEstimator for BlockNativeGasFeeEstimator {
             )));
         }
 
-        let gas_estimates: BlockNativeGasEstimateResult =
-            response.json().await.map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
+        let gas_estimates: BlockNativeGasEstimateResult = response
+            .json()
+            .await
+            .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
 
         gas_estimates.to_base_result()
     }
 
     fn is_chain_supported(&self, chain_id: &ChainId) -> bool {
-        // BlockNative supports major EVM chains
-        // Common supported chains: Ethereum (1), Polygon (137), BSC (56), Optimism (10), Arbitrum (42161)
-        matches!(chain_id.u64(), 1 | 10 | 56 | 137 | 42161 | 8453 | 43114)
+        // BlockNative supports major EVM chains, but only when we actually have a key to call
+        // the endpoint with.
+        self.config.api_key.is_some()
+            && matches!(chain_id.u64(), 1 | 10 | 56 | 137 | 42161 | 8453 | 43114)
     }
 }
@@ -0,0 +1,95 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use super::{
+    base::{BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult},
+    composite::QuorumReport,
+};
+use crate::network::ChainId;
+
+struct CachedEntry {
+    result: GasEstimatorResult,
+    cached_at: Instant,
+}
+
+/// Decorates any `BaseGasFeeEstimator` with a short-lived per-chain cache, so a relayer batching
+/// many transactions per block doesn't re-query a rate-limited third-party API (Infura,
+/// BlockNative) on every single transaction. Wrap the estimator returned by `get_gas_estimator`
+/// with this rather than building TTL caching into each individual estimator.
+pub struct CachedGasFeeEstimator {
+    inner: Arc<dyn BaseGasFeeEstimator + Send + Sync>,
+    ttl: Duration,
+    cache: Mutex<HashMap<ChainId, CachedEntry>>,
+}
+
+impl CachedGasFeeEstimator {
+    pub fn new(inner: Arc<dyn BaseGasFeeEstimator + Send + Sync>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_fresh(&self, chain_id: &ChainId) -> Option<GasEstimatorResult> {
+        let cache = self.cache.lock().await;
+        let entry = cache.get(chain_id)?;
+        if entry.cached_at.elapsed() < self.ttl {
+            return Some(entry.result.clone());
+        }
+        None
+    }
+
+    async fn get_stale(&self, chain_id: &ChainId) -> Option<GasEstimatorResult> {
+        let cache = self.cache.lock().await;
+        cache.get(chain_id).map(|entry| entry.result.clone())
+    }
+
+    async fn set_cached(&self, chain_id: ChainId, result: GasEstimatorResult) {
+        let mut cache = self.cache.lock().await;
+        cache.insert(
+            chain_id,
+            CachedEntry {
+                result,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl BaseGasFeeEstimator for CachedGasFeeEstimator {
+    async fn get_gas_prices(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        if let Some(cached) = self.get_fresh(chain_id).await {
+            return Ok(cached);
+        }
+
+        match self.inner.get_gas_prices(chain_id).await {
+            Ok(result) => {
+                self.set_cached(*chain_id, result.clone()).await;
+                Ok(result)
+            }
+            // The upstream call failed (e.g. rate limited); serve a stale value rather than
+            // propagating the error if we have one, since a slightly outdated fee is still
+            // better than none.
+            Err(err) => self.get_stale(chain_id).await.ok_or(err),
+        }
+    }
+
+    fn is_chain_supported(&self, chain_id: &ChainId) -> bool {
+        self.inner.is_chain_supported(chain_id)
+    }
+
+    fn last_quorum_report(&self, chain_id: &ChainId) -> Option<QuorumReport> {
+        self.inner.last_quorum_report(chain_id)
+    }
+}
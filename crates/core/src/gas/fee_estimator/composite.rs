@@ -0,0 +1,584 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+
+use super::base::{BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult, GasPriceResult};
+use crate::{
+    gas::types::{MaxFee, MaxPriorityFee},
+    network::ChainId,
+};
+
+/// Selects the composite estimator in the `gas_providers` yaml config, combining whichever of
+/// the other configured providers are present into one `CompositeGasFeeEstimator` per
+/// `CompositeModeConfig`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CompositeGasProviderSetupConfig {
+    #[serde(default)]
+    pub mode: CompositeModeConfig,
+    /// Trust weight per configured source, in the same order the sources are combined in (see
+    /// `get_gas_estimator`). Only consulted in `CompositeModeConfig::WeightedMedian`; every
+    /// source without an entry here (all of them, if omitted) gets weight 1.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub weights: Option<Vec<u32>>,
+    /// The K in a K-of-N quorum. Only consulted in `CompositeModeConfig::Quorum`.
+    #[serde(default = "default_quorum_min_responses")]
+    pub quorum_min_responses: usize,
+    /// Only consulted in `CompositeModeConfig::Quorum`.
+    #[serde(default = "default_quorum_max_deviation_bps")]
+    pub quorum_max_deviation_bps: u64,
+}
+
+fn default_quorum_min_responses() -> usize {
+    1
+}
+
+fn default_quorum_max_deviation_bps() -> u64 {
+    u64::MAX
+}
+
+/// Yaml-facing counterpart of `CompositeMode`, kept separate so the runtime type doesn't need to
+/// derive `Serialize`/`Deserialize` just to be configurable.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CompositeModeConfig {
+    #[default]
+    Priority,
+    Median,
+    WeightedMedian,
+    Quorum,
+}
+
+impl From<CompositeModeConfig> for CompositeMode {
+    fn from(value: CompositeModeConfig) -> Self {
+        match value {
+            CompositeModeConfig::Priority => CompositeMode::Priority,
+            CompositeModeConfig::Median => CompositeMode::Median,
+            CompositeModeConfig::WeightedMedian => CompositeMode::WeightedMedian,
+            CompositeModeConfig::Quorum => CompositeMode::Quorum,
+        }
+    }
+}
+
+/// Rolling success/latency tracking for a single provider on a single chain, used to demote a
+/// consistently-failing provider without permanently blacklisting it.
+#[derive(Debug, Clone, Default)]
+struct ProviderHealth {
+    successes: u32,
+    failures: u32,
+    avg_latency_ms: f64,
+}
+
+impl ProviderHealth {
+    fn record_success(&mut self, latency: Duration) {
+        self.successes += 1;
+        let latency_ms = latency.as_millis() as f64;
+        self.avg_latency_ms = if self.successes == 1 {
+            latency_ms
+        } else {
+            // Exponential moving average so recent latency dominates old samples.
+            self.avg_latency_ms * 0.7 + latency_ms * 0.3
+        };
+    }
+
+    fn record_failure(&mut self) {
+        self.failures += 1;
+    }
+
+    /// Higher is healthier. A provider with no history scores neutrally so it still gets tried.
+    /// A small latency penalty breaks ties between equally-reliable providers in favour of the
+    /// faster one.
+    fn score(&self) -> f64 {
+        let total = self.successes + self.failures;
+        if total == 0 {
+            return 0.5;
+        }
+        let success_rate = self.successes as f64 / total as f64;
+        let latency_penalty = (self.avg_latency_ms / 1_000_000.0).min(0.01);
+        success_rate - latency_penalty
+    }
+}
+
+/// How `CompositeGasFeeEstimator` reconciles results across its inner estimators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompositeMode {
+    /// Try inner estimators in priority order (best-health first), returning the first
+    /// success with a sane (non-zero) result.
+    Priority,
+    /// Query every supporting estimator concurrently and take the per-speed median, so a
+    /// single outlier provider can't skew the relayed fee.
+    Median,
+    /// Like `Median`, but each surviving estimator's result counts towards the consensus in
+    /// proportion to its configured weight instead of equally, so a trusted primary oracle can
+    /// be given more say while still being checked against the others. See
+    /// `CompositeGasFeeEstimator::new_with_weighted_median`.
+    WeightedMedian,
+    /// Requires at least a configured number of estimators to respond (a K-of-N quorum),
+    /// discards any response whose medium-tier max fee deviates from the provisional median by
+    /// more than a configured deviation, then takes the median of the remaining agreeing
+    /// sources. Never hard-fails just because quorum wasn't reached or a source was dropped -
+    /// instead it falls back to whatever agreeing sources it has and flags the result as
+    /// degraded via `CompositeGasFeeEstimator::last_quorum_report`, so operators can detect a
+    /// misbehaving oracle without the relayer going offline. See
+    /// `CompositeGasFeeEstimator::new_with_quorum`.
+    Quorum,
+}
+
+/// A point-in-time summary of how a `CompositeMode::Quorum` reconciliation went for one chain,
+/// kept around so operators can tell a healthy consensus apart from a degraded one that happened
+/// to still produce a usable price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuorumReport {
+    /// How many configured sources responded with a sane result at all.
+    pub responded: usize,
+    /// How many sources were required to respond (the K in K-of-N) for the quorum to be met.
+    pub required: usize,
+    /// How many of the responding sources agreed with the provisional median within the
+    /// configured deviation and contributed to the final price. When every responding source
+    /// is an outlier, all of them are used for the price and are counted both here and in
+    /// `outlier_sources`.
+    pub agreeing_sources: usize,
+    /// How many responding sources were dropped as outliers.
+    pub outlier_sources: usize,
+    /// `true` if quorum wasn't met, or if any source was dropped as an outlier - a signal that
+    /// the reconciled price was produced in a degraded state and the sources should be
+    /// investigated.
+    pub degraded: bool,
+}
+
+/// Routes gas price requests across multiple providers per chain, tracking rolling
+/// success/latency so a consistently-failing provider is demoted rather than retried first
+/// every time. Replaces the old pattern of a single estimator either succeeding or erroring
+/// outright, with no way to fall through to an alternative source.
+pub struct CompositeGasFeeEstimator {
+    estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>>,
+    /// Per-estimator trust weight, indexed the same as `estimators`. Only consulted in
+    /// `CompositeMode::WeightedMedian`; an estimator with no entry here is treated as weight 1.
+    weights: Vec<u32>,
+    mode: CompositeMode,
+    health: Mutex<HashMap<(u64, usize), ProviderHealth>>,
+    /// Minimum number of sources that must respond for `CompositeMode::Quorum` to consider
+    /// consensus met, rather than merely degraded.
+    quorum_min_responses: usize,
+    /// Maximum allowed deviation, in basis points of the provisional median, before a
+    /// `CompositeMode::Quorum` response is dropped as an outlier.
+    quorum_max_deviation_bps: u64,
+    quorum_reports: Mutex<HashMap<u64, QuorumReport>>,
+}
+
+impl CompositeGasFeeEstimator {
+    pub fn new(estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>>) -> Self {
+        Self {
+            estimators,
+            weights: Vec::new(),
+            mode: CompositeMode::Priority,
+            health: Mutex::new(HashMap::new()),
+            quorum_min_responses: 1,
+            quorum_max_deviation_bps: u64::MAX,
+            quorum_reports: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn new_with_mode(
+        estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>>,
+        mode: CompositeMode,
+    ) -> Self {
+        Self {
+            estimators,
+            weights: Vec::new(),
+            mode,
+            health: Mutex::new(HashMap::new()),
+            quorum_min_responses: 1,
+            quorum_max_deviation_bps: u64::MAX,
+            quorum_reports: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Builds a `CompositeMode::WeightedMedian` estimator. `weights[i]` is the trust weight for
+    /// `estimators[i]`; an operator who trusts a primary oracle more heavily can give it a
+    /// higher weight while still cross-checking it against the others.
+    pub fn new_with_weighted_median(
+        estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>>,
+        weights: Vec<u32>,
+    ) -> Self {
+        Self {
+            estimators,
+            weights,
+            mode: CompositeMode::WeightedMedian,
+            health: Mutex::new(HashMap::new()),
+            quorum_min_responses: 1,
+            quorum_max_deviation_bps: u64::MAX,
+            quorum_reports: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Builds a `CompositeMode::Quorum` estimator. `min_responses` is the K in a K-of-N quorum
+    /// over `estimators`; `max_deviation_bps` is how far (in basis points of the provisional
+    /// median) a source's medium-tier max fee may stray before it's dropped as an outlier.
+    pub fn new_with_quorum(
+        estimators: Vec<Arc<dyn BaseGasFeeEstimator + Send + Sync>>,
+        min_responses: usize,
+        max_deviation_bps: u64,
+    ) -> Self {
+        Self {
+            estimators,
+            weights: Vec::new(),
+            mode: CompositeMode::Quorum,
+            health: Mutex::new(HashMap::new()),
+            quorum_min_responses: min_responses,
+            quorum_max_deviation_bps: max_deviation_bps,
+            quorum_reports: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the most recent quorum reconciliation summary for `chain_id`, if one has run.
+    /// Operators can poll this to detect a source consistently driving the oracle into degraded
+    /// mode.
+    pub fn last_quorum_report(&self, chain_id: &ChainId) -> Option<QuorumReport> {
+        self.quorum_reports
+            .lock()
+            .expect("composite gas estimator quorum report lock poisoned")
+            .get(&chain_id.u64())
+            .cloned()
+    }
+
+    fn supporting_indices(&self, chain_id: &ChainId) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .estimators
+            .iter()
+            .enumerate()
+            .filter(|(_, estimator)| estimator.is_chain_supported(chain_id))
+            .map(|(index, _)| index)
+            .collect();
+
+        let health = self
+            .health
+            .lock()
+            .expect("composite gas estimator health lock poisoned");
+        indices.sort_by(|a, b| {
+            let score_a = health
+                .get(&(chain_id.u64(), *a))
+                .map(|h| h.score())
+                .unwrap_or(0.5);
+            let score_b = health
+                .get(&(chain_id.u64(), *b))
+                .map(|h| h.score())
+                .unwrap_or(0.5);
+            score_b
+                .partial_cmp(&score_a)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        indices
+    }
+
+    fn is_sane(result: &GasEstimatorResult) -> bool {
+        [
+            &result.slow,
+            &result.medium,
+            &result.fast,
+            &result.super_fast,
+        ]
+        .iter()
+        .all(|tier| tier.max_fee.into_u128() > 0)
+    }
+
+    async fn try_provider(&self, index: usize, chain_id: &ChainId) -> Option<GasEstimatorResult> {
+        let started = Instant::now();
+        match self.estimators[index].get_gas_prices(chain_id).await {
+            Ok(result) if Self::is_sane(&result) => {
+                self.health
+                    .lock()
+                    .expect("composite gas estimator health lock poisoned")
+                    .entry((chain_id.u64(), index))
+                    .or_default()
+                    .record_success(started.elapsed());
+                Some(result)
+            }
+            _ => {
+                self.health
+                    .lock()
+                    .expect("composite gas estimator health lock poisoned")
+                    .entry((chain_id.u64(), index))
+                    .or_default()
+                    .record_failure();
+                None
+            }
+        }
+    }
+
+    async fn get_priority(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        for index in self.supporting_indices(chain_id) {
+            if let Some(result) = self.try_provider(index, chain_id).await {
+                return Ok(result);
+            }
+        }
+
+        Err(GasEstimatorError::CustomError(format!(
+            "No gas fee estimator could produce a price for chain {}",
+            chain_id
+        )))
+    }
+
+    async fn get_median(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let indices = self.supporting_indices(chain_id);
+        let results = join_all(
+            indices
+                .into_iter()
+                .map(|index| self.try_provider(index, chain_id)),
+        )
+        .await;
+        let results: Vec<GasEstimatorResult> = results.into_iter().flatten().collect();
+
+        if results.is_empty() {
+            return Err(GasEstimatorError::CustomError(format!(
+                "No gas fee estimator could produce a price for chain {}",
+                chain_id
+            )));
+        }
+
+        Ok(median_of_results(&results))
+    }
+
+    async fn get_quorum(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let indices = self.supporting_indices(chain_id);
+        let results = join_all(
+            indices
+                .into_iter()
+                .map(|index| self.try_provider(index, chain_id)),
+        )
+        .await;
+        let results: Vec<GasEstimatorResult> = results.into_iter().flatten().collect();
+        let responded = results.len();
+
+        if results.is_empty() {
+            self.record_quorum_report(
+                chain_id,
+                QuorumReport {
+                    responded: 0,
+                    required: self.quorum_min_responses,
+                    agreeing_sources: 0,
+                    outlier_sources: 0,
+                    degraded: true,
+                },
+            );
+
+            return Err(GasEstimatorError::CustomError(format!(
+                "No gas fee estimator could produce a price for chain {}",
+                chain_id
+            )));
+        }
+
+        // Judge outliers off the medium-tier max fee so a single source is either kept or
+        // dropped as a whole, rather than mixing-and-matching which tier came from which
+        // provider - that would produce a result no single source actually agreed with.
+        let mut medium_max_fees: Vec<u128> =
+            results.iter().map(|r| r.medium.max_fee.into_u128()).collect();
+        medium_max_fees.sort_unstable();
+        let provisional_median = median(&medium_max_fees);
+
+        let (agreeing, outliers): (Vec<GasEstimatorResult>, Vec<GasEstimatorResult>) =
+            results.into_iter().partition(|r| {
+                deviation_bps(r.medium.max_fee.into_u128(), provisional_median)
+                    <= self.quorum_max_deviation_bps
+            });
+
+        // If every source was flagged as an outlier (e.g. two sources far apart, whose averaged
+        // provisional median sits equidistant from both), fall back to using them all rather
+        // than returning no price at all.
+        let survivors =
+            if agreeing.is_empty() { outliers.as_slice() } else { agreeing.as_slice() };
+
+        let degraded = responded < self.quorum_min_responses || !outliers.is_empty();
+
+        self.record_quorum_report(
+            chain_id,
+            QuorumReport {
+                responded,
+                required: self.quorum_min_responses,
+                agreeing_sources: survivors.len(),
+                outlier_sources: outliers.len(),
+                degraded,
+            },
+        );
+
+        Ok(median_of_results(survivors))
+    }
+
+    fn record_quorum_report(&self, chain_id: &ChainId, report: QuorumReport) {
+        self.quorum_reports
+            .lock()
+            .expect("composite gas estimator quorum report lock poisoned")
+            .insert(chain_id.u64(), report);
+    }
+
+    async fn get_weighted_median(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let indices = self.supporting_indices(chain_id);
+        let weighted_results = join_all(indices.into_iter().map(|index| async move {
+            let weight = self.weights.get(index).copied().unwrap_or(1);
+            self.try_provider(index, chain_id)
+                .await
+                .map(|result| (result, weight))
+        }))
+        .await;
+        let weighted_results: Vec<(GasEstimatorResult, u32)> =
+            weighted_results.into_iter().flatten().collect();
+
+        if weighted_results.is_empty() {
+            return Err(GasEstimatorError::CustomError(format!(
+                "No gas fee estimator could produce a price for chain {}",
+                chain_id
+            )));
+        }
+
+        let weighted_tier = |pick: fn(&GasEstimatorResult) -> &GasPriceResult| -> GasPriceResult {
+            let max_fees: Vec<(u128, u32)> = weighted_results
+                .iter()
+                .map(|(r, w)| (pick(r).max_fee.into_u128(), *w))
+                .collect();
+            let priority_fees: Vec<(u128, u32)> = weighted_results
+                .iter()
+                .map(|(r, w)| (pick(r).max_priority_fee.into_u128(), *w))
+                .collect();
+            let l1_data_fees: Vec<(u128, u32)> = weighted_results
+                .iter()
+                .filter_map(|(r, w)| pick(r).l1_data_fee.map(|fee| (fee, *w)))
+                .collect();
+
+            GasPriceResult {
+                max_priority_fee: MaxPriorityFee::new(weighted_median(&priority_fees)),
+                max_fee: MaxFee::new(weighted_median(&max_fees)),
+                min_wait_time_estimate: None,
+                max_wait_time_estimate: None,
+                l1_data_fee: if l1_data_fees.is_empty() {
+                    None
+                } else {
+                    Some(weighted_median(&l1_data_fees))
+                },
+            }
+        };
+
+        Ok(GasEstimatorResult {
+            slow: weighted_tier(|r| &r.slow),
+            medium: weighted_tier(|r| &r.medium),
+            fast: weighted_tier(|r| &r.fast),
+            super_fast: weighted_tier(|r| &r.super_fast),
+        })
+    }
+}
+
+/// Takes the per-speed-tier median across `results`, used by both `CompositeMode::Median` and
+/// the surviving (non-outlier) sources of `CompositeMode::Quorum`.
+fn median_of_results(results: &[GasEstimatorResult]) -> GasEstimatorResult {
+    let median_tier = |pick: fn(&GasEstimatorResult) -> &GasPriceResult| -> GasPriceResult {
+        let mut max_fees: Vec<u128> = results.iter().map(|r| pick(r).max_fee.into_u128()).collect();
+        let mut priority_fees: Vec<u128> =
+            results.iter().map(|r| pick(r).max_priority_fee.into_u128()).collect();
+        max_fees.sort_unstable();
+        priority_fees.sort_unstable();
+
+        let mut l1_data_fees: Vec<u128> =
+            results.iter().filter_map(|r| pick(r).l1_data_fee).collect();
+        l1_data_fees.sort_unstable();
+
+        GasPriceResult {
+            max_priority_fee: MaxPriorityFee::new(median(&priority_fees)),
+            max_fee: MaxFee::new(median(&max_fees)),
+            min_wait_time_estimate: None,
+            max_wait_time_estimate: None,
+            l1_data_fee: if l1_data_fees.is_empty() { None } else { Some(median(&l1_data_fees)) },
+        }
+    };
+
+    GasEstimatorResult {
+        slow: median_tier(|r| &r.slow),
+        medium: median_tier(|r| &r.medium),
+        fast: median_tier(|r| &r.fast),
+        super_fast: median_tier(|r| &r.super_fast),
+    }
+}
+
+/// Deviation of `value` from `reference`, in basis points. A zero reference is treated as
+/// infinitely far away rather than dividing by zero, so a single degenerate zero-fee source
+/// can't make everything else look like an outlier.
+fn deviation_bps(value: u128, reference: u128) -> u64 {
+    if reference == 0 {
+        return if value == 0 { 0 } else { u64::MAX };
+    }
+
+    let diff = value.abs_diff(reference);
+    ((diff.saturating_mul(10_000)) / reference).min(u64::MAX as u128) as u64
+}
+
+/// Sorted-input median; averages the two middle elements for an even-sized sample.
+fn median(sorted: &[u128]) -> u128 {
+    let len = sorted.len();
+    if len % 2 == 1 {
+        sorted[len / 2]
+    } else {
+        (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+    }
+}
+
+/// Weighted median over `(value, weight)` pairs: sorts by value, then returns the first value
+/// whose cumulative weight reaches half the total weight.
+fn weighted_median(pairs: &[(u128, u32)]) -> u128 {
+    let mut sorted = pairs.to_vec();
+    sorted.sort_unstable_by_key(|(value, _)| *value);
+
+    let total_weight: u64 = sorted.iter().map(|(_, weight)| *weight as u64).sum();
+    if total_weight == 0 {
+        return median(&sorted.iter().map(|(value, _)| *value).collect::<Vec<_>>());
+    }
+
+    let mut running = 0u64;
+    for (value, weight) in &sorted {
+        running += *weight as u64;
+        if running * 2 >= total_weight {
+            return *value;
+        }
+    }
+
+    sorted.last().map(|(value, _)| *value).unwrap_or(0)
+}
+
+#[async_trait]
+impl BaseGasFeeEstimator for CompositeGasFeeEstimator {
+    async fn get_gas_prices(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        match self.mode {
+            CompositeMode::Priority => self.get_priority(chain_id).await,
+            CompositeMode::Median => self.get_median(chain_id).await,
+            CompositeMode::WeightedMedian => self.get_weighted_median(chain_id).await,
+            CompositeMode::Quorum => self.get_quorum(chain_id).await,
+        }
+    }
+
+    fn is_chain_supported(&self, chain_id: &ChainId) -> bool {
+        self.estimators
+            .iter()
+            .any(|estimator| estimator.is_chain_supported(chain_id))
+    }
+
+    fn last_quorum_report(&self, chain_id: &ChainId) -> Option<QuorumReport> {
+        CompositeGasFeeEstimator::last_quorum_report(self, chain_id)
+    }
+}
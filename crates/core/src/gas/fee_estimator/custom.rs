@@ -54,6 +54,7 @@ impl CustomGasEstimateResult {
             max_fee: MaxFee::new(parse_formatted_gas_to_u128(&speed.suggested_max_fee_per_gas)?),
             min_wait_time_estimate: speed.min_wait_time_estimate,
             max_wait_time_estimate: speed.max_wait_time_estimate,
+            l1_data_fee: None,
         })
     }
 
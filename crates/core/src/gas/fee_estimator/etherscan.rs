@@ -70,6 +70,7 @@ impl EtherscanGasOracleResult {
             max_fee: MaxFee::new(safe_total),
             min_wait_time_estimate: Some(300), // ~5 minutes for safe
             max_wait_time_estimate: Some(600), // ~10 minutes for safe
+            l1_data_fee: None,
         };
 
         let medium_result = GasPriceResult {
@@ -77,6 +78,7 @@ impl EtherscanGasOracleResult {
             max_fee: MaxFee::new(propose_total),
             min_wait_time_estimate: Some(60), // ~1 minute for standard
             max_wait_time_estimate: Some(180), // ~3 minutes for standard
+            l1_data_fee: None,
         };
 
         let fast_result = GasPriceResult {
@@ -84,6 +86,7 @@ impl EtherscanGasOracleResult {
             max_fee: MaxFee::new(fast_total),
             min_wait_time_estimate: Some(15), // ~15 seconds for fast
             max_wait_time_estimate: Some(60), // ~1 minute for fast
+            l1_data_fee: None,
         };
 
         let super_fast_result = GasPriceResult {
@@ -91,6 +94,7 @@ impl EtherscanGasOracleResult {
             max_fee: MaxFee::new(super_fast_total),
             min_wait_time_estimate: Some(5), // ~5 seconds for super fast
             max_wait_time_estimate: Some(15), // ~15 seconds for super fast
+            l1_data_fee: None,
         };
 
         Ok(GasEstimatorResult {
@@ -1,98 +1,225 @@
 use std::sync::Arc;
 
-use alloy::{eips::BlockNumberOrTag, primitives::utils::parse_units};
+use alloy::{
+    eips::{BlockId, BlockNumberOrTag},
+    primitives::utils::parse_units,
+};
 use async_trait::async_trait;
 
 use super::base::{BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult, GasPriceResult};
 use crate::{
     gas::types::{MaxFee, MaxPriorityFee},
     network::ChainId,
-    provider::RelayerProvider,
+    provider::{NodeClient, RelayerProvider},
 };
 
+/// Reward percentiles requested from `eth_feeHistory`, mapped directly to the slow/medium/fast/
+/// super_fast tiers.
+const REWARD_PERCENTILES: [f64; 4] = [25.0, 50.0, 75.0, 90.0];
+
+/// Trailing blocks sampled from `eth_feeHistory`.
+const FEE_HISTORY_BLOCK_COUNT: u64 = 10;
+
 #[derive(Clone)]
 pub struct FallbackGasFeeEstimator {
     provider: Arc<RelayerProvider>,
+    supports_eip1559: bool,
+    node_client: NodeClient,
 }
 
 impl FallbackGasFeeEstimator {
-    pub fn new(provider: Arc<RelayerProvider>) -> Self {
-        FallbackGasFeeEstimator { provider }
+    pub fn new(
+        provider: Arc<RelayerProvider>,
+        supports_eip1559: bool,
+        node_client: NodeClient,
+    ) -> Self {
+        FallbackGasFeeEstimator {
+            provider,
+            supports_eip1559,
+            node_client,
+        }
+    }
+
+    /// Sources a single legacy `gasPrice` for chains that don't implement the London fork,
+    /// instead of computing a base-fee/priority-fee split that wouldn't apply.
+    async fn estimate_legacy_gas_price(&self) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let gas_price = self
+            .provider
+            .get_gas_price()
+            .await
+            .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
+
+        Ok(GasEstimatorResult::legacy_from_gas_price(gas_price))
+    }
+
+    /// Projects the base fee `blocks` blocks into the future using the EIP-1559 recurrence
+    /// `base_{k+1} = base_k * (1 + (ratio - 1) * 0.125)`, where `ratio` is `gas_used/gas_target`.
+    fn project_base_fee(base_fee: u128, ratio: f64, blocks: u32) -> u128 {
+        let mut fee = base_fee as f64;
+        for _ in 0..blocks {
+            fee = (fee * (1.0 + (ratio - 1.0) * 0.125)).max(0.0);
+        }
+        fee as u128
+    }
+
+    /// Derives an inclusion-probability wait window for a capped `max_fee`, instead of echoing
+    /// a single provider number into both `min_wait_time_estimate` and `max_wait_time_estimate`.
+    ///
+    /// `min_wait_time_estimate` walks the base-fee trajectory forward under the recently
+    /// observed `gas_used/gas_target` ratio; `max_wait_time_estimate` assumes the pessimistic
+    /// case of the network staying fully congested (ratio≈2, i.e. +12.5% base fee per block),
+    /// capped at `MAX_FORECAST_BLOCKS` so a fee that can never catch up still returns a bound.
+    fn forecast_wait_window(
+        base_fee: u128,
+        tip: u128,
+        max_fee: u128,
+        recent_ratio: f64,
+        block_time_secs: i64,
+    ) -> (i64, i64) {
+        const MAX_FORECAST_BLOCKS: u32 = 50;
+        const CONGESTED_RATIO: f64 = 2.0;
+
+        let blocks_until_affordable = |ratio: f64| -> u32 {
+            (0..=MAX_FORECAST_BLOCKS)
+                .find(|&k| Self::project_base_fee(base_fee, ratio, k) + tip <= max_fee)
+                .unwrap_or(MAX_FORECAST_BLOCKS)
+        };
+
+        let min_blocks = blocks_until_affordable(recent_ratio);
+        let max_blocks = blocks_until_affordable(CONGESTED_RATIO).max(min_blocks);
+
+        (
+            min_blocks as i64 * block_time_secs,
+            max_blocks as i64 * block_time_secs,
+        )
+    }
+
+    /// Default priority fee used when `eth_feeHistory` comes back with no reward data at all
+    /// for a percentile column, instead of leaving that tier's tip at zero.
+    fn default_priority_fee(chain_id: &ChainId) -> u128 {
+        if chain_id.u64() == 1 {
+            parse_units("2", "gwei").unwrap().try_into().unwrap() // 2 gwei default for Ethereum
+        } else {
+            parse_units("0.01", "gwei").unwrap().try_into().unwrap() // 0.01 gwei default for other chains
+        }
+    }
+
+    /// Applies the EIP-1559 base-fee update rule to project the *next* block's base fee from the
+    /// latest block's `base_fee`, `gas_used`, and `gas_limit`, instead of reusing the latest
+    /// block's own (already one-block-stale) base fee.
+    ///
+    /// `gas_target` is half the gas limit (the `ELASTICITY_MULTIPLIER` of 2); the base fee moves
+    /// by up to `1/BASE_FEE_MAX_CHANGE_DENOMINATOR` (1/8) of itself per block, proportional to how
+    /// far `gas_used` is from that target, with a minimum change of 1 wei whenever usage and
+    /// target differ.
+    fn project_next_block_base_fee(base_fee: u128, gas_used: u128, gas_limit: u128) -> u128 {
+        const ELASTICITY_MULTIPLIER: u128 = 2;
+        const BASE_FEE_MAX_CHANGE_DENOMINATOR: u128 = 8;
+
+        let gas_target = gas_limit / ELASTICITY_MULTIPLIER;
+        if gas_target == 0 {
+            return base_fee;
+        }
+
+        match gas_used.cmp(&gas_target) {
+            std::cmp::Ordering::Equal => base_fee,
+            std::cmp::Ordering::Greater => {
+                let gas_used_delta = gas_used - gas_target;
+                let delta =
+                    ((base_fee * gas_used_delta) / gas_target / BASE_FEE_MAX_CHANGE_DENOMINATOR)
+                        .max(1);
+                base_fee.saturating_add(delta)
+            }
+            std::cmp::Ordering::Less => {
+                let gas_used_delta = gas_target - gas_used;
+                let delta =
+                    ((base_fee * gas_used_delta) / gas_target / BASE_FEE_MAX_CHANGE_DENOMINATOR)
+                        .max(1);
+                base_fee.saturating_sub(delta)
+            }
+        }
+    }
+
+    /// Projects the next block's base fee from the latest on-chain block, for use when
+    /// `eth_feeHistory` isn't available and we can't rely on the node to have done this for us.
+    /// Returns `None` if the latest block can't be fetched or has no base fee (pre-London chain).
+    async fn project_latest_block_base_fee(&self) -> Option<u128> {
+        let block = self
+            .provider
+            .get_block(BlockId::Number(BlockNumberOrTag::Latest))
+            .await
+            .ok()??;
+
+        let base_fee = block.header.base_fee_per_gas? as u128;
+        let gas_used = block.header.gas_used as u128;
+        let gas_limit = block.header.gas_limit as u128;
+
+        Some(Self::project_next_block_base_fee(
+            base_fee, gas_used, gas_limit,
+        ))
+    }
+
+    /// Median of the reward values at `percentile_index` across the blocks that returned one.
+    fn median_reward_at(rewards: &[Vec<u128>], percentile_index: usize) -> Option<u128> {
+        let mut values: Vec<u128> = rewards
+            .iter()
+            .filter_map(|row| row.get(percentile_index).copied())
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        values.sort_unstable();
+        Some(values[values.len() / 2])
     }
 
+    /// Returns the per-tier priority fee (slow/medium/fast/super_fast, in that order), the
+    /// pending (next-block) base fee, and the recent `gas_used/gas_target` ratio that feeds the
+    /// base-fee trajectory forecast used to derive wait-time estimates.
+    ///
+    /// Queries `eth_feeHistory` once for the `REWARD_PERCENTILES` columns and maps each
+    /// directly onto a speed tier, instead of medianing every transaction out of full blocks
+    /// (far fewer bytes over the wire, and cleaner percentiles since the node computes them).
     async fn estimate_with_fee_history(
         &self,
         chain_id: &ChainId,
-    ) -> Result<(u128, u128), GasEstimatorError> {
-        let past_blocks = if chain_id.u64() == 1 || chain_id.u64() == 11155111 { 20 } else { 60 };
-        let reward_percentile =
-            if chain_id.u64() == 1 || chain_id.u64() == 11155111 { 60.0 } else { 25.0 };
-
+    ) -> Result<([u128; 4], u128, f64), GasEstimatorError> {
         let fee_history = self
             .provider
-            .get_fee_history(past_blocks, BlockNumberOrTag::Latest, &[reward_percentile])
+            .get_fee_history(
+                FEE_HISTORY_BLOCK_COUNT,
+                BlockNumberOrTag::Pending,
+                &REWARD_PERCENTILES,
+            )
             .await
             .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
 
-        let base_fee_per_gas = match fee_history.latest_block_base_fee() {
+        let pending_base_fee = match fee_history.latest_block_base_fee() {
             Some(base_fee) if base_fee != 0 => base_fee,
-            _ => self
-                .provider
-                .get_block_by_number(BlockNumberOrTag::Latest)
-                .await
-                .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?
-                .ok_or_else(|| {
-                    GasEstimatorError::CustomError("Latest block not found".to_string())
-                })?
-                .header
-                .base_fee_per_gas
-                .ok_or_else(|| {
-                    GasEstimatorError::CustomError("EIP-1559 not supported".to_string())
-                })?
-                .into(),
-        };
-
-        let priority_fee = if let Some(rewards) = &fee_history.reward {
-            if !rewards.is_empty() {
-                let mut all_rewards: Vec<u128> = rewards
-                    .iter()
-                    .filter_map(|block_rewards| block_rewards.first().copied())
-                    .collect();
-
-                if !all_rewards.is_empty() {
-                    all_rewards.sort();
-                    let median_idx = all_rewards.len() / 2;
-                    all_rewards[median_idx]
-                } else {
-                    if chain_id.u64() == 1 {
-                        parse_units("2", "gwei").unwrap().try_into().unwrap() // 2 gwei default for Ethereum
-                    } else {
-                        parse_units("0.01", "gwei").unwrap().try_into().unwrap()
-                        // 0.01 gwei default for other chains
-                    }
-                }
-            } else {
-                if chain_id.u64() == 1 {
-                    parse_units("2", "gwei").unwrap().try_into().unwrap() // 2 gwei default for Ethereum
-                } else {
-                    parse_units("0.01", "gwei").unwrap().try_into().unwrap() // 0.01 gwei default for other chains
-                }
-            }
-        } else {
-            if chain_id.u64() == 1 {
-                parse_units("2", "gwei").unwrap().try_into().unwrap() // 2 gwei default for Ethereum
-            } else {
-                parse_units("0.01", "gwei").unwrap().try_into().unwrap() // 0.01 gwei default for other chains
+            _ => {
+                return Err(GasEstimatorError::CustomError(
+                    "EIP-1559 not supported".to_string(),
+                ))
             }
         };
 
-        let max_fee = if chain_id.u64() == 1 {
-            (base_fee_per_gas + priority_fee).max(priority_fee * 2) // Original logic for Ethereum
+        let rewards = fee_history.reward.unwrap_or_default();
+        let priority_fees = std::array::from_fn(|i| {
+            Self::median_reward_at(&rewards, i)
+                .unwrap_or_else(|| Self::default_priority_fee(chain_id))
+        });
+
+        // `gas_used_ratio` is `gas_used/gas_limit`; the EIP-1559 target is half the limit, so
+        // the `gas_used/gas_target` ratio the base-fee recurrence expects is double that.
+        let recent_ratio = if fee_history.gas_used_ratio.is_empty() {
+            1.0
         } else {
-            base_fee_per_gas + (priority_fee * 2) // Simplified for other chains
+            (fee_history.gas_used_ratio.iter().sum::<f64>()
+                / fee_history.gas_used_ratio.len() as f64)
+                * 2.0
         };
 
-        Ok((priority_fee, max_fee))
+        Ok((priority_fees, pending_base_fee, recent_ratio))
     }
 }
 
@@ -102,51 +229,79 @@ impl BaseGasFeeEstimator for FallbackGasFeeEstimator {
         &self,
         _chain_id: &ChainId,
     ) -> Result<GasEstimatorResult, GasEstimatorError> {
-        let (base_priority_fee, base_max_fee) =
-            match self.estimate_with_fee_history(_chain_id).await {
-                Ok(fees) => fees,
-                Err(_) => {
-                    let suggested = self
-                        .provider
-                        .estimate_eip1559_fees()
-                        .await
-                        .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
-
-                    let priority_fee = suggested.max_priority_fee_per_gas;
-                    let max_fee = if _chain_id.u64() == 1 {
-                        suggested.max_fee_per_gas.max(priority_fee * 2) // Original logic for Ethereum
-                    } else {
-                        suggested.max_fee_per_gas // Simplified for other chains
-                    };
-                    (priority_fee, max_fee)
-                }
-            };
+        if !self.supports_eip1559 {
+            return self.estimate_legacy_gas_price().await;
+        }
+
+        let fee_history_result = if self.node_client.fee_history_reliable() {
+            self.estimate_with_fee_history(_chain_id).await
+        } else {
+            // Known unreliable on this client - skip straight to the projection fallback
+            // instead of paying for an `eth_feeHistory` call we already know comes back unusable.
+            Err(GasEstimatorError::CustomError("eth_feeHistory unreliable on this client".to_string()))
+        };
+
+        let (priority_fees, base_fee_per_gas, recent_ratio) = match fee_history_result {
+            Ok(fees) => fees,
+            // The node doesn't support eth_feeHistory (or it came back unusable); project
+            // the next block's base fee ourselves from the latest block instead of trusting
+            // the provider's own suggestion, which tends to lag a climbing base fee.
+            Err(_) => {
+                let suggested = self
+                    .provider
+                    .estimate_eip1559_fees()
+                    .await
+                    .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
+
+                let priority_fee = suggested.max_priority_fee_per_gas;
+                let base_fee_per_gas = self
+                    .project_latest_block_base_fee()
+                    .await
+                    .unwrap_or_else(|| suggested.max_fee_per_gas.saturating_sub(priority_fee) / 2);
+
+                (
+                    [
+                        (priority_fee * 80) / 100,
+                        priority_fee,
+                        (priority_fee * 130) / 100,
+                        (priority_fee * 180) / 100,
+                    ],
+                    base_fee_per_gas,
+                    1.0, // No fee-history sample to derive a ratio from; assume a half-full block.
+                )
+            }
+        };
+
+        let block_time_secs = if _chain_id.u64() == 1 { 12 } else { 2 };
+
+        // `max_fee` doubles the pending base fee before adding the tier's priority fee, so the
+        // fee stays valid even if the base fee keeps rising for several blocks in a row.
+        let tier = |priority_fee: u128| {
+            let max_fee = base_fee_per_gas
+                .saturating_mul(2)
+                .saturating_add(priority_fee);
+            let (min_wait, max_wait) = Self::forecast_wait_window(
+                base_fee_per_gas,
+                priority_fee,
+                max_fee,
+                recent_ratio,
+                block_time_secs,
+            );
+
+            GasPriceResult {
+                max_priority_fee: MaxPriorityFee::new(priority_fee),
+                max_fee: MaxFee::new(max_fee),
+                min_wait_time_estimate: Some(min_wait),
+                max_wait_time_estimate: Some(max_wait),
+                l1_data_fee: None,
+            }
+        };
 
         Ok(GasEstimatorResult {
-            slow: GasPriceResult {
-                max_priority_fee: MaxPriorityFee::new((base_priority_fee * 80) / 100), // -20%
-                max_fee: MaxFee::new((base_max_fee * 90) / 100),                       // -10%
-                min_wait_time_estimate: Some(120),                                     // 2 minutes
-                max_wait_time_estimate: Some(300),                                     // 5 minutes
-            },
-            medium: GasPriceResult {
-                max_priority_fee: MaxPriorityFee::new(base_priority_fee),
-                max_fee: MaxFee::new(base_max_fee),
-                min_wait_time_estimate: Some(30),  // 30 seconds
-                max_wait_time_estimate: Some(120), // 2 minutes
-            },
-            fast: GasPriceResult {
-                max_priority_fee: MaxPriorityFee::new((base_priority_fee * 130) / 100), // +30%
-                max_fee: MaxFee::new((base_max_fee * 120) / 100),                       // +20%
-                min_wait_time_estimate: Some(15), // 15 seconds
-                max_wait_time_estimate: Some(60), // 1 minute
-            },
-            super_fast: GasPriceResult {
-                max_priority_fee: MaxPriorityFee::new((base_priority_fee * 180) / 100), // +80%
-                max_fee: MaxFee::new((base_max_fee * 150) / 100),                       // +50%
-                min_wait_time_estimate: Some(5),                                        // 5 seconds
-                max_wait_time_estimate: Some(30), // 30 seconds
-            },
+            slow: tier(priority_fees[0]),
+            medium: tier(priority_fees[1]),
+            fast: tier(priority_fees[2]),
+            super_fast: tier(priority_fees[3]),
         })
     }
 
@@ -0,0 +1,248 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use alloy::eips::BlockNumberOrTag;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::base::{BaseGasFeeEstimator, GasEstimatorError, GasEstimatorResult, GasPriceResult};
+use crate::{
+    gas::types::{MaxFee, MaxPriorityFee},
+    network::ChainId,
+    provider::RelayerProvider,
+};
+
+/// Selects the native `eth_feeHistory` estimator in the `gas_providers` yaml config. It needs no
+/// credentials since it reads directly from the network's own RPC provider.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeeHistoryGasProviderSetupConfig {
+    pub enabled: bool,
+    /// Floor applied to every tier's computed priority fee, in wei. Some chains' validators
+    /// routinely report a zero reward even under real load, which would otherwise send
+    /// transactions with `maxPriorityFeePerGas: 0` and starve them of inclusion; defaults to 0
+    /// (no floor) so chains with meaningful reward data are unaffected.
+    #[serde(default)]
+    pub min_priority_fee_wei: u128,
+}
+
+/// Number of trailing blocks sampled from `eth_feeHistory`.
+const FEE_HISTORY_BLOCK_COUNT: u64 = 20;
+
+/// Reward percentiles requested from `eth_feeHistory`, mapped directly to the slow/medium/fast/
+/// super_fast tiers.
+const REWARD_PERCENTILES: [f64; 4] = [10.0, 50.0, 90.0, 99.0];
+
+/// How long a chain's computed `GasEstimatorResult` is reused before another `eth_feeHistory`
+/// call is made, mirroring the short-lived, per-key TTL used by the network cache layer's
+/// `disabled_networks` cache.
+const CACHE_TTL: Duration = Duration::from_secs(12);
+
+struct CachedEstimate {
+    result: GasEstimatorResult,
+    expires_at: Instant,
+}
+
+/// Estimates gas fees purely from recent on-chain block history via `eth_feeHistory`, without
+/// relying on any third-party gas API. The priority fee for a speed tier is the `gasUsedRatio`-
+/// weighted average of that tier's reward percentile across the sampled blocks, and
+/// `max_fee_per_gas` doubles the predicted next-block base fee before adding the priority fee,
+/// so the fee stays valid even if the base fee keeps rising for several blocks in a row.
+pub struct FeeHistoryGasFeeEstimator {
+    provider: Arc<RelayerProvider>,
+    supports_eip1559: bool,
+    min_priority_fee_wei: u128,
+    cache: Mutex<HashMap<ChainId, CachedEstimate>>,
+}
+
+impl FeeHistoryGasFeeEstimator {
+    pub fn new(provider: Arc<RelayerProvider>, supports_eip1559: bool) -> Self {
+        Self::new_with_min_priority_fee(provider, supports_eip1559, 0)
+    }
+
+    pub fn new_with_min_priority_fee(
+        provider: Arc<RelayerProvider>,
+        supports_eip1559: bool,
+        min_priority_fee_wei: u128,
+    ) -> Self {
+        Self {
+            provider,
+            supports_eip1559,
+            min_priority_fee_wei,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get_cached(&self, chain_id: &ChainId) -> Option<GasEstimatorResult> {
+        let cache = self.cache.lock().await;
+        let cached = cache.get(chain_id)?;
+        if cached.expires_at > Instant::now() {
+            return Some(cached.result.clone());
+        }
+        None
+    }
+
+    async fn set_cached(&self, chain_id: ChainId, result: GasEstimatorResult) {
+        let mut cache = self.cache.lock().await;
+        cache.insert(
+            chain_id,
+            CachedEstimate {
+                result,
+                expires_at: Instant::now() + CACHE_TTL,
+            },
+        );
+    }
+
+    /// Sources a single legacy `gasPrice`, used both for chains that don't implement the London
+    /// fork and as the fallback when `eth_feeHistory` comes back without usable reward data.
+    async fn estimate_legacy_gas_price(&self) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let gas_price = self
+            .provider
+            .get_gas_price()
+            .await
+            .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
+
+        Ok(GasEstimatorResult::legacy_from_gas_price(gas_price))
+    }
+
+    /// `gasUsedRatio`-weighted average of the reward values at `percentile_index` across the
+    /// sampled blocks: fuller blocks (higher `gasUsedRatio`) count for more, since their reward
+    /// reflects real fee-market pressure, while a block returning a zero reward (typically an
+    /// empty block with nothing to prioritize) is dropped entirely rather than dragging the
+    /// average toward zero. Returns `None` if no block has a non-zero reward.
+    fn weighted_average_reward_at(
+        rewards: &[Vec<u128>],
+        gas_used_ratios: &[f64],
+        percentile_index: usize,
+    ) -> Option<u128> {
+        let mut weighted_sum = 0f64;
+        let mut weight_total = 0f64;
+
+        for (index, row) in rewards.iter().enumerate() {
+            let reward = match row.get(percentile_index).copied() {
+                Some(reward) if reward > 0 => reward,
+                _ => continue,
+            };
+
+            // A missing or non-positive ratio still carries weight - the reward itself is real
+            // usable signal - it just can't be weighted by how full the block was.
+            let weight = gas_used_ratios.get(index).copied().filter(|r| *r > 0.0).unwrap_or(1.0);
+
+            weighted_sum += reward as f64 * weight;
+            weight_total += weight;
+        }
+
+        if weight_total <= 0.0 {
+            return None;
+        }
+
+        Some((weighted_sum / weight_total).round() as u128)
+    }
+
+    /// Builds tiers from the base fee alone (plus the configured priority fee floor, if any),
+    /// used when `eth_feeHistory` returns a base fee but no usable reward data (e.g. an empty
+    /// `reward` array).
+    fn base_fee_only_tiers(&self, base_fee: u128) -> GasEstimatorResult {
+        let tier = || GasPriceResult {
+            max_priority_fee: MaxPriorityFee::new(self.min_priority_fee_wei),
+            max_fee: MaxFee::new(base_fee.saturating_mul(2).saturating_add(self.min_priority_fee_wei)),
+            min_wait_time_estimate: None,
+            max_wait_time_estimate: None,
+            l1_data_fee: None,
+        };
+
+        GasEstimatorResult {
+            slow: tier(),
+            medium: tier(),
+            fast: tier(),
+            super_fast: tier(),
+        }
+    }
+
+    async fn estimate_from_fee_history(
+        &self,
+        _chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        let fee_history = self
+            .provider
+            .get_fee_history(
+                FEE_HISTORY_BLOCK_COUNT,
+                BlockNumberOrTag::Latest,
+                &REWARD_PERCENTILES,
+            )
+            .await
+            .map_err(|e| GasEstimatorError::CustomError(e.to_string()))?;
+
+        // No base fee at all means a pre-London chain; there's nothing to build tiers from but
+        // a flat `eth_gasPrice`.
+        let predicted_base_fee = match fee_history.next_block_base_fee() {
+            Some(base_fee) if base_fee != 0 => base_fee,
+            _ => return self.estimate_legacy_gas_price().await,
+        };
+
+        let rewards = match &fee_history.reward {
+            Some(rewards) if !rewards.is_empty() => rewards,
+            // Reward history unavailable (e.g. an empty `reward` array on a quiet chain); fall
+            // back to base-fee-only tiers rather than guessing a priority fee.
+            _ => return Ok(self.base_fee_only_tiers(predicted_base_fee)),
+        };
+        let gas_used_ratios = &fee_history.gas_used_ratio;
+
+        let (slow_fee, medium_fee, fast_fee, super_fast_fee) = match (
+            Self::weighted_average_reward_at(rewards, gas_used_ratios, 0),
+            Self::weighted_average_reward_at(rewards, gas_used_ratios, 1),
+            Self::weighted_average_reward_at(rewards, gas_used_ratios, 2),
+            Self::weighted_average_reward_at(rewards, gas_used_ratios, 3),
+        ) {
+            (Some(slow), Some(medium), Some(fast), Some(super_fast)) => (slow, medium, fast, super_fast),
+            _ => return Ok(self.base_fee_only_tiers(predicted_base_fee)),
+        };
+
+        let tier = |priority_fee: u128| {
+            let priority_fee = priority_fee.max(self.min_priority_fee_wei);
+            GasPriceResult {
+                max_priority_fee: MaxPriorityFee::new(priority_fee),
+                max_fee: MaxFee::new(
+                    predicted_base_fee.saturating_mul(2).saturating_add(priority_fee),
+                ),
+                min_wait_time_estimate: None,
+                max_wait_time_estimate: None,
+                l1_data_fee: None,
+            }
+        };
+
+        Ok(GasEstimatorResult {
+            slow: tier(slow_fee),
+            medium: tier(medium_fee),
+            fast: tier(fast_fee),
+            super_fast: tier(super_fast_fee),
+        })
+    }
+}
+
+#[async_trait]
+impl BaseGasFeeEstimator for FeeHistoryGasFeeEstimator {
+    async fn get_gas_prices(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<GasEstimatorResult, GasEstimatorError> {
+        if !self.supports_eip1559 {
+            return self.estimate_legacy_gas_price().await;
+        }
+
+        if let Some(cached) = self.get_cached(chain_id).await {
+            return Ok(cached);
+        }
+
+        let result = self.estimate_from_fee_history(chain_id).await?;
+        self.set_cached(*chain_id, result.clone()).await;
+        Ok(result)
+    }
+
+    fn is_chain_supported(&self, _: &ChainId) -> bool {
+        true
+    }
+}
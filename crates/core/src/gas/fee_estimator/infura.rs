@@ -17,6 +17,68 @@ pub struct InfuraGasProviderSetupConfig {
     pub enabled: bool,
     pub api_key: String,
     pub secret: String,
+    #[serde(default)]
+    pub tier_multipliers: GasTierMultipliers,
+}
+
+/// Fee-percentage and wait-percentage adjustment applied to a single speed tier, e.g. `150`/`80`
+/// scales the tier's fees up to 150% and its wait-time estimates down to 80%, giving the
+/// transaction more headroom to land sooner.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct GasTierMultiplier {
+    pub fee_percentage: u64,
+    pub wait_percentage: u64,
+}
+
+impl GasTierMultiplier {
+    pub const fn unchanged() -> Self {
+        Self {
+            fee_percentage: 100,
+            wait_percentage: 100,
+        }
+    }
+}
+
+impl Default for GasTierMultiplier {
+    fn default() -> Self {
+        Self::unchanged()
+    }
+}
+
+/// Per-speed-tier fee/wait adjustments applied on top of Infura's suggested gas fees, so
+/// operators can tune replacement headroom per tier instead of editing the estimator's source.
+/// Defaults match the historical hardcoded behaviour: slow/medium/fast unchanged, super_fast
+/// bumped to 120% fees / 80% wait time.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GasTierMultipliers {
+    #[serde(default)]
+    pub slow: GasTierMultiplier,
+    #[serde(default)]
+    pub medium: GasTierMultiplier,
+    #[serde(default)]
+    pub fast: GasTierMultiplier,
+    #[serde(default = "GasTierMultipliers::default_super_fast")]
+    pub super_fast: GasTierMultiplier,
+}
+
+impl GasTierMultipliers {
+    fn default_super_fast() -> GasTierMultiplier {
+        GasTierMultiplier {
+            fee_percentage: 120,
+            wait_percentage: 80,
+        }
+    }
+}
+
+impl Default for GasTierMultipliers {
+    fn default() -> Self {
+        Self {
+            slow: GasTierMultiplier::unchanged(),
+            medium: GasTierMultiplier::unchanged(),
+            fast: GasTierMultiplier::unchanged(),
+            super_fast: Self::default_super_fast(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -44,58 +106,63 @@ struct InfuraGasEstimateResult {
 }
 
 impl InfuraGasEstimateResult {
-    /// Converts an Infura gas estimate speed result to the standard gas price result format.
+    /// Converts an Infura gas estimate speed result to the standard gas price result format,
+    /// applying `multiplier`'s fee/wait percentages uniformly rather than only to a single
+    /// hardcoded tier.
     ///
     /// # Arguments
     /// * `speed` - The Infura gas estimate data for a specific speed
-    /// * `is_super_fast` - Whether this is for the super fast tier (applies 120% multiplier)
+    /// * `multiplier` - The fee/wait percentage adjustment to apply for this tier
     ///
     /// # Returns
     /// * `Ok(GasPriceResult)` - The converted standard gas price result
     /// * `Err(UnitsError)` - If parsing the gas price strings fails or overflow occurs
     fn gas_price_result(
         speed: &InfuraGasEstimateSpeedResult,
-        is_super_fast: bool,
+        multiplier: GasTierMultiplier,
     ) -> Result<GasPriceResult, UnitsError> {
-        let (priority_multiplier, wait_multiplier) = if is_super_fast {
-            (120, 80) // 120% for fees, 80% for wait times
-        } else {
-            (100, 100) // No adjustment for other speeds
-        };
-
         let max_priority_fee =
             parse_formatted_gas_to_u128(&speed.suggested_max_priority_fee_per_gas)?
-                .checked_mul(priority_multiplier)
+                .checked_mul(multiplier.fee_percentage as u128)
                 .and_then(|v| v.checked_div(100))
                 .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))?;
 
         let max_fee = parse_formatted_gas_to_u128(&speed.suggested_max_fee_per_gas)?
-            .checked_mul(priority_multiplier)
+            .checked_mul(multiplier.fee_percentage as u128)
             .and_then(|v| v.checked_div(100))
             .ok_or(UnitsError::ParseSigned(ParseSignedError::IntegerOverflow))?;
 
         Ok(GasPriceResult {
             max_priority_fee: MaxPriorityFee::new(max_priority_fee),
             max_fee: MaxFee::new(max_fee),
-            min_wait_time_estimate: Some(speed.min_wait_time_estimate * wait_multiplier / 100),
-            max_wait_time_estimate: Some(speed.max_wait_time_estimate * wait_multiplier / 100),
+            min_wait_time_estimate: Some(
+                speed.min_wait_time_estimate * multiplier.wait_percentage as i64 / 100,
+            ),
+            max_wait_time_estimate: Some(
+                speed.max_wait_time_estimate * multiplier.wait_percentage as i64 / 100,
+            ),
+            l1_data_fee: None,
         })
     }
 
     /// Converts the Infura gas estimate result to the standard gas estimator result format.
     ///
-    /// Maps Infura's low/medium/high speeds to slow/medium/fast, and creates super_fast
-    /// by applying a multiplier to the high speed estimates.
+    /// Maps Infura's low/medium/high speeds to slow/medium/fast, derives super_fast from the
+    /// high speed estimate, and applies `tier_multipliers`'s per-tier fee/wait adjustment to
+    /// each of the four tiers.
     ///
     /// # Returns
     /// * `Ok(GasEstimatorResult)` - The converted standard gas estimator result
     /// * `Err(UnitsError)` - If parsing any of the gas price strings fails
-    pub fn to_base_result(&self) -> Result<GasEstimatorResult, UnitsError> {
+    pub fn to_base_result(
+        &self,
+        tier_multipliers: &GasTierMultipliers,
+    ) -> Result<GasEstimatorResult, UnitsError> {
         Ok(GasEstimatorResult {
-            slow: Self::gas_price_result(&self.low, false)?,
-            medium: Self::gas_price_result(&self.medium, false)?,
-            fast: Self::gas_price_result(&self.high, false)?,
-            super_fast: Self::gas_price_result(&self.high, true)?,
+            slow: Self::gas_price_result(&self.low, tier_multipliers.slow)?,
+            medium: Self::gas_price_result(&self.medium, tier_multipliers.medium)?,
+            fast: Self::gas_price_result(&self.high, tier_multipliers.fast)?,
+            super_fast: Self::gas_price_result(&self.high, tier_multipliers.super_fast)?,
         })
     }
 }
@@ -106,6 +173,7 @@ pub struct InfuraGasFeeEstimator {
     supported_chains: Vec<ChainId>,
     api_key: String,
     secret: String,
+    tier_multipliers: GasTierMultipliers,
 }
 
 impl InfuraGasFeeEstimator {
@@ -114,10 +182,11 @@ impl InfuraGasFeeEstimator {
     /// # Arguments
     /// * `api_key` - The Infura API key for authentication
     /// * `secret` - The Infura API secret for authentication
+    /// * `tier_multipliers` - Per-speed-tier fee/wait percentage adjustment
     ///
     /// # Returns
     /// * A new `InfuraGasFeeEstimator` instance configured with all supported chains
-    pub fn new(api_key: &str, secret: &str) -> Self {
+    pub fn new(api_key: &str, secret: &str, tier_multipliers: GasTierMultipliers) -> Self {
         Self {
             base_url: "https://gas.api.infura.io/networks".to_string(),
             supported_chains: vec![
@@ -147,6 +216,7 @@ impl InfuraGasFeeEstimator {
             ],
             api_key: api_key.to_string(),
             secret: secret.to_string(),
+            tier_multipliers,
         }
     }
 
@@ -203,7 +273,9 @@ impl BaseGasFeeEstimator for InfuraGasFeeEstimator {
     ) -> Result<GasEstimatorResult, GasEstimatorError> {
         let gas_estimate_result = self.request_gas_estimate(chain_id).await?;
 
-        Ok(gas_estimate_result.to_base_result().map_err(GasEstimatorError::UnitsError)?)
+        Ok(gas_estimate_result
+            .to_base_result(&self.tier_multipliers)
+            .map_err(GasEstimatorError::UnitsError)?)
     }
 
     fn is_chain_supported(&self, chain_id: &ChainId) -> bool {
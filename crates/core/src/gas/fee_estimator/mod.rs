@@ -6,6 +6,15 @@ pub use base::{
 mod blocknative;
 pub use blocknative::BlockNativeGasProviderSetupConfig;
 
+mod cached;
+pub use cached::CachedGasFeeEstimator;
+
+mod composite;
+pub use composite::{
+    CompositeGasFeeEstimator, CompositeGasProviderSetupConfig, CompositeMode, CompositeModeConfig,
+    QuorumReport,
+};
+
 mod custom;
 pub use custom::CustomGasFeeEstimator;
 
@@ -14,8 +23,11 @@ pub use etherscan::EtherscanGasProviderSetupConfig;
 
 mod fallback;
 
+mod fee_history;
+pub use fee_history::FeeHistoryGasProviderSetupConfig;
+
 mod infura;
-pub use infura::InfuraGasProviderSetupConfig;
+pub use infura::{GasTierMultiplier, GasTierMultipliers, InfuraGasProviderSetupConfig};
 
 mod tenderly;
 pub use tenderly::TenderlyGasProviderSetupConfig;
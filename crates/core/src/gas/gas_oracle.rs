@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use super::fee_estimator::{GasEstimatorResult, GasPriceResult};
+use super::fee_estimator::{GasEstimatorResult, GasPriceResult, QuorumReport};
 use crate::{network::ChainId, provider::EvmProvider, transaction::types::TransactionSpeed};
 use tokio::{
     sync::Mutex,
@@ -10,11 +10,17 @@ use tracing::{error, info};
 
 pub struct GasOracleCache {
     gas_prices: Mutex<HashMap<ChainId, GasEstimatorResult>>,
+    /// Most recent `CompositeMode::Quorum` reconciliation summary per chain, for chains whose
+    /// configured gas estimator is a quorum composite. Absent for every other chain/mode.
+    quorum_reports: Mutex<HashMap<ChainId, QuorumReport>>,
 }
 
 impl GasOracleCache {
     pub fn new() -> Self {
-        GasOracleCache { gas_prices: Mutex::new(HashMap::new()) }
+        GasOracleCache {
+            gas_prices: Mutex::new(HashMap::new()),
+            quorum_reports: Mutex::new(HashMap::new()),
+        }
     }
 
     async fn update_gas_price(&self, chain_id: ChainId, gas_price: GasEstimatorResult) {
@@ -22,11 +28,30 @@ impl GasOracleCache {
         cache.insert(chain_id, gas_price);
     }
 
+    async fn update_quorum_report(&self, chain_id: ChainId, report: Option<QuorumReport>) {
+        let mut cache = self.quorum_reports.lock().await;
+        match report {
+            Some(report) => {
+                cache.insert(chain_id, report);
+            }
+            None => {
+                cache.remove(&chain_id);
+            }
+        }
+    }
+
     pub async fn get_gas_price(&self, chain_id: &ChainId) -> Option<GasEstimatorResult> {
         let cache = self.gas_prices.lock().await;
         cache.get(chain_id).cloned()
     }
 
+    /// The most recent quorum reconciliation summary recorded for `chain_id`, if its configured
+    /// gas estimator is a `CompositeGasFeeEstimator` running in quorum mode.
+    pub async fn get_quorum_report(&self, chain_id: &ChainId) -> Option<QuorumReport> {
+        let cache = self.quorum_reports.lock().await;
+        cache.get(chain_id).cloned()
+    }
+
     pub async fn get_gas_price_for_speed(
         &self,
         chain_id: &ChainId,
@@ -58,7 +83,11 @@ pub async fn gas_oracle(
             let gas_price_result = provider.calculate_gas_price().await;
             match gas_price_result {
                 Ok(gas_price) => {
-                    cache.lock().await.update_gas_price(provider.chain_id, gas_price).await;
+                    let cache = cache.lock().await;
+                    cache.update_gas_price(provider.chain_id, gas_price).await;
+                    cache
+                        .update_quorum_report(provider.chain_id, provider.last_quorum_report())
+                        .await;
                 }
                 Err(err) => {
                     error!(
@@ -91,7 +120,11 @@ pub async fn gas_oracle(
                 let gas_price_result = provider.calculate_gas_price().await;
                 match gas_price_result {
                     Ok(gas_price) => {
-                        cache.lock().await.update_gas_price(provider.chain_id, gas_price).await;
+                        let cache = cache.lock().await;
+                        cache.update_gas_price(provider.chain_id, gas_price).await;
+                        cache
+                            .update_quorum_report(provider.chain_id, provider.last_quorum_report())
+                            .await;
                     }
                     Err(err) => {
                         error!("Failed to get gas price for provider: {} - error {} - try again in 10s", provider.name, err);
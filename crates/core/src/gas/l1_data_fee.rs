@@ -0,0 +1,90 @@
+use alloy::primitives::{address, Address};
+use alloy::providers::Provider;
+use alloy::rpc::types::serde_helpers::WithOtherFields;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use thiserror::Error;
+
+use crate::{network::ChainId, provider::RelayerProvider};
+
+/// Address of the OP-Stack `GasPriceOracle` predeploy, present on every OP-Stack chain at the
+/// same address regardless of chain id.
+pub const OP_STACK_GAS_PRICE_ORACLE: Address = address!("420000000000000000000000000000000000000F");
+
+/// Chain ids of OP-Stack L2s registered elsewhere in this module (Tenderly's supported chain
+/// list, etc.) where the dominant relaying cost is the L1 data fee rather than L2 execution gas.
+const OP_STACK_CHAIN_IDS: &[u64] = &[
+    10,       // Optimism
+    11155420, // Optimism Sepolia
+    8453,     // Base
+    84532,    // Base Sepolia
+    34443,    // Mode
+    919,      // Mode Sepolia
+    57073,    // Ink
+    763373,   // Ink Sepolia
+    1868,     // Soneium
+    1946,     // Soneium Minato
+    130,      // Unichain
+    1301,     // Unichain Sepolia
+    252,      // Fraxtal
+    2522,     // Fraxtal Holesky
+    81457,    // Blast
+    480,      // Worldchain
+    4801,     // Worldchain Sepolia
+    1923,     // Swellchain
+    1924,     // Swellchain Sepolia
+    1135,     // Lisk
+    4202,     // Lisk Sepolia
+];
+
+sol! {
+    #[sol(rpc)]
+    interface IGasPriceOracle {
+        function getL1Fee(bytes memory _data) external view returns (uint256);
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum L1DataFeeError {
+    #[error("Failed to call GasPriceOracle.getL1Fee: {0}")]
+    CallFailed(String),
+
+    #[error("Failed to decode GasPriceOracle.getL1Fee response: {0}")]
+    DecodeFailed(String),
+
+    #[error("L1 data fee does not fit in u128")]
+    ValueTooLarge,
+}
+
+/// Whether `chain_id` is an OP-Stack L2 where the `GasPriceOracle` predeploy should be consulted
+/// for the L1 data-fee component of a transaction's total cost.
+pub fn is_op_stack_chain(chain_id: &ChainId) -> bool {
+    OP_STACK_CHAIN_IDS.contains(&chain_id.u64())
+}
+
+/// Reads the OP-Stack `GasPriceOracle` predeploy to compute the L1 data-fee contribution for a
+/// transaction with the given calldata. On L2s this can dwarf the L2 execution fee, so total-cost
+/// estimates and balance checks need to add it on top of `GasPriceResult`'s fee rather than
+/// relying on L2 gas alone.
+pub async fn calculate_l1_data_fee(
+    provider: &RelayerProvider,
+    calldata: &[u8],
+) -> Result<u128, L1DataFeeError> {
+    let call = IGasPriceOracle::getL1FeeCall { _data: calldata.to_vec().into() };
+
+    let call_tx = WithOtherFields::new(alloy::rpc::types::TransactionRequest {
+        to: Some(alloy::primitives::TxKind::Call(OP_STACK_GAS_PRICE_ORACLE)),
+        input: Some(call.abi_encode().into()).into(),
+        ..Default::default()
+    });
+
+    let result = provider
+        .call(&call_tx)
+        .await
+        .map_err(|e| L1DataFeeError::CallFailed(e.to_string()))?;
+
+    let decoded = IGasPriceOracle::getL1FeeCall::abi_decode_returns(&result, false)
+        .map_err(|e| L1DataFeeError::DecodeFailed(e.to_string()))?;
+
+    decoded._0.try_into().map_err(|_| L1DataFeeError::ValueTooLarge)
+}
@@ -10,5 +10,10 @@ pub use fee_estimator::*;
 mod gas_oracle;
 pub use gas_oracle::{gas_oracle, GasOracleCache};
 
+mod l1_data_fee;
+pub use l1_data_fee::{
+    calculate_l1_data_fee, is_op_stack_chain, L1DataFeeError, OP_STACK_GAS_PRICE_ORACLE,
+};
+
 mod types;
 pub use types::*;
@@ -9,6 +9,8 @@ pub enum GasProvider {
     INFURA,
     TENDERLY,
     CUSTOM,
+    FEE_HISTORY,
+    COMPOSITE,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +28,8 @@ impl FromStr for GasProvider {
             "INFURA" => Ok(GasProvider::INFURA),
             "TENDERLY" => Ok(GasProvider::TENDERLY),
             "CUSTOM" => Ok(GasProvider::CUSTOM),
+            "FEE_HISTORY" => Ok(GasProvider::FEE_HISTORY),
+            "COMPOSITE" => Ok(GasProvider::COMPOSITE),
             _ => Err(ConversionError { message: format!("Unsupported gas provider: {}", s) }),
         }
     }
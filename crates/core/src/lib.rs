@@ -17,7 +17,7 @@ pub use safe_proxy::{SafeProxyError, SafeProxyManager, SafeTransaction};
 pub use yaml::{
     read, ApiConfig, AwsKmsSigningProviderConfig, GasProviders, NetworkSetupConfig,
     RateLimitConfig, RateLimitWithInterval, RawSigningProviderConfig, SafeProxyConfig, SetupConfig,
-    SigningProvider, UserRateLimitConfig,
+    SigningProvider, TransactionRetentionConfig, UserRateLimitConfig,
 };
 mod shared;
 pub use shared::{common_types, utils::get_chain_id};
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::gas::QuorumReport;
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, network::ChainId};
+use axum::http::HeaderMap;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+/// Retrieves the most recent quorum reconciliation summary for a specific chain via HTTP API.
+///
+/// Only populated for chains whose configured gas estimator is a `CompositeGasFeeEstimator`
+/// running in `CompositeMode::Quorum`; every other chain/mode returns 404.
+pub async fn get_gas_quorum(
+    State(state): State<Arc<AppState>>,
+    Path(chain_id): Path<ChainId>,
+    headers: HeaderMap,
+) -> Result<Json<QuorumReport>, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let quorum_report = state
+        .gas_oracle_cache
+        .lock()
+        .await
+        .get_quorum_report(&chain_id)
+        .await
+        .ok_or(not_found("quorum report not found".to_string()))?;
+
+    Ok(Json(quorum_report))
+}
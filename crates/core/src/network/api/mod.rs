@@ -5,10 +5,12 @@ use axum::{routing::get, Router};
 use crate::app_state::AppState;
 
 mod get_gas_price;
+mod get_gas_quorum;
 mod networks;
 
 pub fn create_network_routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(networks::networks))
         .route("/gas/price/:chain_id", get(get_gas_price::get_gas_price))
+        .route("/gas/price/:chain_id/quorum", get(get_gas_quorum::get_gas_quorum))
 }
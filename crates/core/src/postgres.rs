@@ -48,6 +48,9 @@ pub enum PostgresError {
 
     #[error("Connection pool error: {0}")]
     ConnectionPoolError(#[from] RunError<tokio_postgres::Error>),
+
+    #[error("{0}")]
+    Custom(String),
 }
 
 pub struct PostgresClient {
@@ -1,5 +1,6 @@
 use crate::gas::BLOB_GAS_PER_BLOB;
 use crate::provider::layer_extensions::RpcLoggingLayer;
+use crate::provider::node_client::{detect_node_client, NodeClient};
 use crate::wallet::{
     AwsKmsWalletManager, MnemonicWalletManager, PrivyWalletManager, TurnkeyWalletManager,
     WalletError, WalletManagerTrait,
@@ -8,7 +9,7 @@ use crate::yaml::{AwsKmsSigningProviderConfig, TurnkeySigningProviderConfig};
 use crate::{
     gas::{
         BaseGasFeeEstimator, BlobGasEstimatorResult, BlobGasPriceResult, GasEstimatorError,
-        GasEstimatorResult, GasLimit,
+        GasEstimatorResult, GasLimit, QuorumReport,
     },
     network::ChainId,
     shared::common_types::{EvmAddress, WalletOrProviderError},
@@ -22,10 +23,10 @@ use alloy::rpc::types::serde_helpers::WithOtherFields;
 use alloy::{
     consensus::TypedTransaction,
     dyn_abi::eip712::TypedData,
-    eips::{BlockId, BlockNumberOrTag},
+    eips::{eip2930::AccessList, BlockId, BlockNumberOrTag},
     network::Ethereum,
     network::TransactionBuilderError,
-    primitives::Signature,
+    primitives::{Signature, U256},
     providers::{Provider, ProviderBuilder},
     rpc::types::TransactionRequest,
     signers::local::LocalSignerError,
@@ -45,6 +46,15 @@ use tracing::info;
 
 pub type RelayerProvider = Box<dyn Provider<AnyNetwork> + Send + Sync>;
 
+/// The minimal slice of a block's header needed to walk the canonical chain - its own identity
+/// and a link to its parent - without paying for the rest of the block body.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockHeader {
+    pub number: u64,
+    pub hash: alloy::primitives::B256,
+    pub parent_hash: alloy::primitives::B256,
+}
+
 #[derive(Clone)]
 pub struct EvmProvider {
     rpc_clients: Vec<Arc<RelayerProvider>>,
@@ -56,6 +66,8 @@ pub struct EvmProvider {
     /// this is in milliseconds (min 250ms)
     pub blocks_every: u64,
     pub confirmations: u64,
+    supports_eip1559: bool,
+    node_client: NodeClient,
 }
 
 async fn calculate_block_time_difference(
@@ -70,10 +82,14 @@ async fn calculate_block_time_difference(
     }
 
     let latest = provider
-        .get_block(BlockId::Number(BlockNumberOrTag::Number(latest_block_number - 12)))
+        .get_block(BlockId::Number(BlockNumberOrTag::Number(
+            latest_block_number - 12,
+        )))
         .await?;
     let earliest = provider
-        .get_block(BlockId::Number(BlockNumberOrTag::Number(latest_block_number - 13)))
+        .get_block(BlockId::Number(BlockNumberOrTag::Number(
+            latest_block_number - 13,
+        )))
         .await?;
 
     let latest = latest.ok_or(RpcError::Transport(TransportErrorKind::Custom(
@@ -115,10 +131,13 @@ pub async fn create_retry_client(rpc_url: &str) -> Result<Arc<RelayerProvider>,
     let logging_layer = RpcLoggingLayer::new(rpc_url.to_string());
     let http = Http::with_client(client_with_auth, rpc_url);
     let retry_layer = RetryBackoffLayer::new(5000, 1000, 660);
-    let rpc_client =
-        RpcClient::builder().layer(retry_layer).layer(logging_layer).transport(http, false);
-    let provider =
-        ProviderBuilder::new().network::<AnyNetwork>().connect_client(rpc_client.clone());
+    let rpc_client = RpcClient::builder()
+        .layer(retry_layer)
+        .layer(logging_layer)
+        .transport(http, false);
+    let provider = ProviderBuilder::new()
+        .network::<AnyNetwork>()
+        .connect_client(rpc_client.clone());
 
     Ok(Arc::new(Box::new(provider)))
 }
@@ -196,8 +215,9 @@ impl EvmProvider {
         wallet_manager: Arc<dyn WalletManagerTrait>,
         gas_estimator: Arc<dyn BaseGasFeeEstimator + Send + Sync>,
     ) -> Result<Self, EvmProviderNewError> {
-        let provider =
-            create_retry_client(&network_setup_config.provider_urls[0]).await.map_err(|e| {
+        let provider = create_retry_client(&network_setup_config.provider_urls[0])
+            .await
+            .map_err(|e| {
                 EvmProviderNewError::HttpProviderCantBeCreated(
                     network_setup_config.provider_urls[0].clone(),
                     e.to_string(),
@@ -205,7 +225,10 @@ impl EvmProvider {
             })?;
 
         let chain_id = ChainId::new(
-            provider.get_chain_id().await.map_err(EvmProviderNewError::ProviderError)?,
+            provider
+                .get_chain_id()
+                .await
+                .map_err(EvmProviderNewError::ProviderError)?,
         );
 
         let mut providers: Vec<Arc<RelayerProvider>> = vec![provider.clone()];
@@ -215,6 +238,9 @@ impl EvmProvider {
             })?);
         }
 
+        let node_client = detect_node_client(&provider).await;
+        info!("Detected node client for {}: {:?}", network_setup_config.name, node_client);
+
         Ok(EvmProvider {
             blocks_every: calculate_block_time_difference(&provider)
                 .await
@@ -226,6 +252,8 @@ impl EvmProvider {
             name: network_setup_config.name.to_string(),
             provider_urls: network_setup_config.provider_urls.to_owned(),
             confirmations: network_setup_config.confirmations.unwrap_or(12),
+            supports_eip1559: network_setup_config.supports_eip1559,
+            node_client,
         })
     }
 
@@ -236,29 +264,65 @@ impl EvmProvider {
     }
 
     pub async fn create_wallet(&self, wallet_index: u32) -> Result<EvmAddress, WalletError> {
-        self.wallet_manager.create_wallet(wallet_index, &self.chain_id).await
+        self.wallet_manager
+            .create_wallet(wallet_index, &self.chain_id)
+            .await
     }
 
     pub async fn get_address(&self, wallet_index: u32) -> Result<EvmAddress, WalletError> {
-        self.wallet_manager.get_address(wallet_index, &self.chain_id).await
+        self.wallet_manager
+            .get_address(wallet_index, &self.chain_id)
+            .await
     }
 
     pub async fn get_receipt(
         &self,
         transaction_hash: &TransactionHash,
     ) -> Result<Option<AnyTransactionReceipt>, RpcError<TransportErrorKind>> {
-        let receipt =
-            self.rpc_client().get_transaction_receipt(transaction_hash.into_alloy_hash()).await?;
+        let receipt = self
+            .rpc_client()
+            .get_transaction_receipt(transaction_hash.into_alloy_hash())
+            .await?;
 
         Ok(receipt)
     }
 
+    /// Fetches just the header (number, hash, parent hash) of the block at `block_id`, without
+    /// pulling the full block body. Returns `None` if the node doesn't have that block (pruned,
+    /// or not yet mined).
+    pub async fn get_block_header(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<BlockHeader>, RpcError<TransportErrorKind>> {
+        let block = self.rpc_client().get_block(block_id).await?;
+
+        Ok(block.map(|block| BlockHeader {
+            number: block.header.number,
+            hash: block.header.hash,
+            parent_hash: block.header.parent_hash,
+        }))
+    }
+
+    /// Fetches the header of the current chain head.
+    pub async fn get_latest_block_header(
+        &self,
+    ) -> Result<BlockHeader, RpcError<TransportErrorKind>> {
+        self.get_block_header(BlockId::Number(BlockNumberOrTag::Latest)).await?.ok_or(
+            RpcError::Transport(TransportErrorKind::Custom(
+                "Latest block not found".to_string().into(),
+            )),
+        )
+    }
+
     pub async fn get_nonce(
         &self,
         wallet_index: &u32,
     ) -> Result<TransactionNonce, WalletOrProviderError> {
-        let address =
-            self.wallet_manager.get_address(*wallet_index, &self.chain_id).await.map_err(|e| {
+        let address = self
+            .wallet_manager
+            .get_address(*wallet_index, &self.chain_id)
+            .await
+            .map_err(|e| {
                 WalletOrProviderError::InternalError(format!("Failed to get address: {}", e))
             })?;
 
@@ -316,7 +380,9 @@ impl EvmProvider {
         wallet_index: &u32,
         transaction: &TypedTransaction,
     ) -> Result<Signature, WalletError> {
-        self.wallet_manager.sign_transaction(*wallet_index, transaction, &self.chain_id).await
+        self.wallet_manager
+            .sign_transaction(*wallet_index, transaction, &self.chain_id)
+            .await
     }
 
     pub async fn sign_text(
@@ -332,7 +398,9 @@ impl EvmProvider {
         wallet_index: &u32,
         typed_data: &TypedData,
     ) -> Result<Signature, WalletError> {
-        self.wallet_manager.sign_typed_data(*wallet_index, typed_data).await
+        self.wallet_manager
+            .sign_typed_data(*wallet_index, typed_data)
+            .await
     }
 
     pub async fn estimate_gas(
@@ -351,18 +419,74 @@ impl EvmProvider {
         Ok(GasLimit::new(result as u128))
     }
 
+    /// Generates an EIP-2930 access list for `transaction` via `eth_createAccessList` and keeps
+    /// it only when it actually lowers total gas, since warming storage slots that aren't
+    /// re-read later just adds the list's own calldata/storage cost for no benefit.
+    ///
+    /// Returns `None` when the node doesn't lower gas with the suggested list, so callers can
+    /// fall back to sending without one. An RPC error from either call is returned as `Err`.
+    pub async fn generate_access_list(
+        &self,
+        transaction: &TypedTransaction,
+        from: &EvmAddress,
+    ) -> Result<Option<AccessList>, RpcError<TransportErrorKind>> {
+        let mut request: TransactionRequest = transaction.clone().into();
+        request.from = Some(from.into_address());
+        let request_with_other = WithOtherFields::new(request);
+
+        let gas_without_access_list = self
+            .rpc_client()
+            .estimate_gas(request_with_other.clone())
+            .await?;
+
+        let access_list_result = self
+            .rpc_client()
+            .create_access_list(request_with_other)
+            .await?;
+
+        if access_list_result.gas_used < U256::from(gas_without_access_list) {
+            Ok(Some(access_list_result.access_list))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub async fn calculate_gas_price(&self) -> Result<GasEstimatorResult, GasEstimatorError> {
         self.gas_estimator.get_gas_prices(&self.chain_id).await
     }
 
+    /// The most recent quorum reconciliation summary for this provider's chain, if its
+    /// configured gas estimator is a `CompositeGasFeeEstimator` running in quorum mode.
+    pub fn last_quorum_report(&self) -> Option<QuorumReport> {
+        self.gas_estimator.last_quorum_report(&self.chain_id)
+    }
+
     pub async fn get_balance(
         &self,
         address: &EvmAddress,
     ) -> Result<alloy::primitives::U256, RpcError<TransportErrorKind>> {
-        let balance = self.rpc_client().get_balance(address.into_address()).await?;
+        let balance = self
+            .rpc_client()
+            .get_balance(address.into_address())
+            .await?;
         Ok(balance)
     }
 
+    /// Checks whether `address` has contract code deployed on this network.
+    ///
+    /// EIP-3607 forbids transactions originating from accounts that carry code, so an address
+    /// this returns `true` for can never act as a relayer/user signing identity on this chain.
+    pub async fn has_contract_code(
+        &self,
+        address: &EvmAddress,
+    ) -> Result<bool, RpcError<TransportErrorKind>> {
+        let code = self
+            .rpc_client()
+            .get_code_at(address.into_address())
+            .await?;
+        Ok(!code.is_empty())
+    }
+
     /// Checks if the current network supports blob transactions (EIP-4844).
     pub fn supports_blob_transactions(&self) -> bool {
         matches!(
@@ -398,12 +522,18 @@ impl EvmProvider {
                 blob_gas_price: super_fast_price,
                 total_fee_for_blob: super_fast_total,
             },
-            fast: BlobGasPriceResult { blob_gas_price: fast_price, total_fee_for_blob: fast_total },
+            fast: BlobGasPriceResult {
+                blob_gas_price: fast_price,
+                total_fee_for_blob: fast_total,
+            },
             medium: BlobGasPriceResult {
                 blob_gas_price: medium_price,
                 total_fee_for_blob: medium_total,
             },
-            slow: BlobGasPriceResult { blob_gas_price: slow_price, total_fee_for_blob: slow_total },
+            slow: BlobGasPriceResult {
+                blob_gas_price: slow_price,
+                total_fee_for_blob: slow_total,
+            },
             base_fee_per_blob_gas,
             timestamp: chrono::Utc::now().timestamp() as u64,
         })
@@ -412,4 +542,14 @@ impl EvmProvider {
     pub fn supports_blobs(&self) -> bool {
         self.wallet_manager.supports_blobs()
     }
+
+    /// Whether this network implements the London fork (EIP-1559). When `false`, the
+    /// transaction builder must emit a legacy type-0 transaction instead of type-2.
+    pub fn supports_eip1559(&self) -> bool {
+        self.supports_eip1559
+    }
+
+    pub fn node_client(&self) -> &NodeClient {
+        &self.node_client
+    }
 }
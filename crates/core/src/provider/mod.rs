@@ -6,13 +6,16 @@ use crate::{gas::get_gas_estimator, network::ChainId, SetupConfig, SigningProvid
 
 mod evm_provider;
 mod layer_extensions;
+mod node_client;
 
 use self::evm_provider::EvmProviderNewError;
 use crate::gas::GasEstimatorError;
 use crate::wallet::get_mnemonic_from_signing_key;
 pub use evm_provider::{
-    create_retry_client, EvmProvider, RelayerProvider, RetryClientError, SendTransactionError,
+    create_retry_client, BlockHeader, EvmProvider, RelayerProvider, RetryClientError,
+    SendTransactionError,
 };
+pub use node_client::{detect_node_client, NodeClient};
 
 #[derive(Error, Debug)]
 pub enum LoadProvidersError {
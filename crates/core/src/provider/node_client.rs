@@ -0,0 +1,73 @@
+use alloy::providers::Provider;
+use alloy::transports::{RpcError, TransportErrorKind};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::RelayerProvider;
+
+/// Execution client backing a network, classified from its `web3_clientVersion` string so
+/// feature paths that differ across clients (fee estimation quirks) can adapt instead of
+/// assuming one client's behaviour.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NodeClient {
+    Geth,
+    Erigon,
+    Nethermind,
+    Besu,
+    Reth,
+    Anvil,
+    /// Responded to `web3_clientVersion` with something we don't recognise - the raw string is
+    /// kept for diagnostics. Feature gates treat this like any client other than Besu, so
+    /// `fee_history_reliable` returns true for it.
+    Unknown(String),
+}
+
+impl NodeClient {
+    /// Classifies a client from its `web3_clientVersion` response, e.g.
+    /// `"Geth/v1.13.14-stable/linux-amd64/go1.21.6"` or `"reth/v0.2.0-beta.6/x86_64-unknown-linux-gnu"`.
+    /// Matching is case-insensitive since clients don't agree on casing.
+    pub fn from_client_version(client_version: &str) -> Self {
+        let lower = client_version.to_lowercase();
+
+        if lower.contains("geth") {
+            NodeClient::Geth
+        } else if lower.contains("erigon") {
+            NodeClient::Erigon
+        } else if lower.contains("nethermind") {
+            NodeClient::Nethermind
+        } else if lower.contains("besu") {
+            NodeClient::Besu
+        } else if lower.contains("reth") {
+            NodeClient::Reth
+        } else if lower.contains("anvil") {
+            NodeClient::Anvil
+        } else {
+            NodeClient::Unknown(client_version.to_string())
+        }
+    }
+
+    /// Whether `eth_feeHistory` can be trusted to return usable reward percentiles. Besu's
+    /// history endpoint has historically returned empty reward columns on some versions, so
+    /// it's treated as unreliable and gas estimation skips `eth_feeHistory` and goes straight to
+    /// projecting the next block's base fee from the latest block, instead of paying for a call
+    /// that's known to come back empty.
+    pub fn fee_history_reliable(&self) -> bool {
+        !matches!(self, NodeClient::Besu)
+    }
+}
+
+/// Probes the network for which execution client is behind `provider` by calling
+/// `web3_clientVersion`. Never fails startup over this - an RPC error or an unparseable response
+/// just yields [`NodeClient::Unknown`], since detection is an optimisation, not a requirement.
+pub async fn detect_node_client(provider: &RelayerProvider) -> NodeClient {
+    let client_version: Result<String, RpcError<TransportErrorKind>> =
+        provider.client().request("web3_clientVersion", ()).await;
+
+    match client_version {
+        Ok(version) => NodeClient::from_client_version(&version),
+        Err(e) => {
+            warn!("Could not determine node client via web3_clientVersion: {}", e);
+            NodeClient::Unknown("unresponsive".to_string())
+        }
+    }
+}
@@ -15,7 +15,10 @@ use crate::{
     network::ChainId,
     provider::find_provider_for_chain_id,
     relayer::{cache::invalidate_relayer_cache, types::RelayerId},
-    transaction::{queue_system::TransactionsQueueSetup, NonceManager},
+    transaction::{
+        queue_system::{NonceCap, TransactionsQueueSetup},
+        NonceManager,
+    },
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -49,6 +52,8 @@ pub async fn clone_relayer(
         )
         .await?;
 
+    let lease_epoch = state.db.claim_new_relayer_for_node(&relayer.id, &state.node_id).await?;
+
     let current_nonce = provider.get_nonce(&relayer).await?;
 
     let id = relayer.id;
@@ -63,6 +68,14 @@ pub async fn clone_relayer(
     let max_gas_price_multiplier =
         network_config.map(|config| config.max_gas_price_multiplier).unwrap_or(2);
 
+    let per_relayer_max_inflight =
+        network_config.map(|config| config.per_relayer_max_inflight).unwrap_or(1000);
+
+    let nonce_cap = network_config
+        .and_then(|config| config.max_future_nonces)
+        .map(NonceCap::new)
+        .unwrap_or_default();
+
     state
         .transactions_queues
         .lock()
@@ -78,6 +91,10 @@ pub async fn clone_relayer(
                 state.safe_proxy_manager.clone(),
                 gas_bump_config,
                 max_gas_price_multiplier,
+                nonce_cap,
+                per_relayer_max_inflight,
+                state.node_id,
+                lease_epoch,
             ),
             state.transactions_queues.clone(),
         )
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::relayer::cache::invalidate_relayer_cache;
+use crate::relayer::get_relayer::relayer_exists;
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, relayer::types::RelayerId};
+
+/// Clears a relayer's on-chain allowlist contract address. `refuse_service` has no effect while no
+/// contract is configured.
+pub async fn delete_relay_whitelist_contract(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+) -> Result<StatusCode, HttpError> {
+    let exists = relayer_exists(&state.db, &state.cache, &relayer_id).await?;
+    if exists {
+        state.db.update_relayer_whitelist_contract_address(&relayer_id, None).await?;
+        invalidate_relayer_cache(&state.cache, &relayer_id).await;
+
+        if let Ok(queue) =
+            state.transactions_queues.lock().await.get_transactions_queue_unsafe(&relayer_id)
+        {
+            queue.lock().await.set_whitelist_contract_address(None);
+        }
+        state.transactions_queues.lock().await.invalidate_onchain_allowlist_cache().await;
+
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found("Relayer does not exist".to_string()))
+    }
+}
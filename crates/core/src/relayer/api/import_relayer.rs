@@ -64,7 +64,9 @@ pub async fn import_relayer(
 
     let provider = find_provider_for_chain_id(&state.evm_providers, &chain_id)
         .await
-        .ok_or(not_found("Could not find provider for the chain id".to_string()))?;
+        .ok_or(not_found(
+            "Could not find provider for the chain id".to_string(),
+        ))?;
 
     // Check if the provider supports key import
     if !provider.supports_key_import() {
@@ -75,9 +77,30 @@ pub async fn import_relayer(
         ));
     }
 
+    // EIP-3607: an address with deployed contract code can never be a valid transaction
+    // sender, so importing it as a relayer would silently never be able to send anything.
+    if provider
+        .has_contract_code(&request.address)
+        .await
+        .map_err(|e| {
+            internal_server_error(Some(format!(
+                "Failed to check address for contract code: {}",
+                e
+            )))
+        })?
+    {
+        return Err(bad_request(format!(
+            "Address {} has contract code deployed on chain {} and cannot be used as a relayer signing address (EIP-3607)",
+            request.address, chain_id
+        )));
+    }
+
     // Check if a relayer with this address already exists for this chain
-    if let Some(existing) =
-        state.db.get_relayer_by_address(&request.address, &chain_id).await.map_err(|e| {
+    if let Some(existing) = state
+        .db
+        .get_relayer_by_address(&request.address, &chain_id)
+        .await
+        .map_err(|e| {
             internal_server_error(Some(format!("Failed to check for existing relayer: {}", e)))
         })?
     {
@@ -91,9 +114,13 @@ pub async fn import_relayer(
     let _lock = state.relayer_creation_mutex.lock().await;
 
     // Get the next available wallet_index for this chain
-    let wallet_index = state.db.get_next_wallet_index(&chain_id).await.map_err(|e| {
-        internal_server_error(Some(format!("Failed to get next wallet index: {}", e)))
-    })?;
+    let wallet_index = state
+        .db
+        .get_next_wallet_index(&chain_id)
+        .await
+        .map_err(|e| {
+            internal_server_error(Some(format!("Failed to get next wallet index: {}", e)))
+        })?;
 
     info!(
         "Importing key {} as relayer '{}' with wallet_index {} on chain {}",
@@ -11,14 +11,20 @@ mod add_allowlist_address;
 mod clone_relayer;
 mod create_relayer;
 mod delete_allowlist_address;
+mod delete_relay_whitelist_contract;
 mod delete_relayer;
 mod get_allowlist_addresses;
 mod get_relayer_api;
 mod get_relayers;
 mod pause_relayer;
 mod unpause_relayer;
+mod update_relay_auto_access_list;
+mod update_relay_default_access_list;
 mod update_relay_eip1559_status;
+mod update_relay_envelope_type;
 mod update_relay_max_gas_price;
+mod update_relay_refuse_service;
+mod update_relay_whitelist_contract;
 
 // Re-export public types from endpoint modules
 pub use clone_relayer::CloneRelayerRequest;
@@ -26,20 +32,27 @@ pub use create_relayer::{CreateRelayerRequest, CreateRelayerResult};
 pub use get_allowlist_addresses::GetAllowlistAddressesQuery;
 pub use get_relayer_api::GetRelayerResult;
 pub use get_relayers::GetRelayersQuery;
+pub use update_relay_default_access_list::UpdateRelayDefaultAccessListRequest;
 
 // Import handler functions
 use add_allowlist_address::add_allowlist_address;
 use clone_relayer::clone_relayer;
 use create_relayer::create_relayer;
 use delete_allowlist_address::delete_allowlist_address;
+use delete_relay_whitelist_contract::delete_relay_whitelist_contract;
 use delete_relayer::delete_relayer;
 use get_allowlist_addresses::get_allowlist_addresses;
 use get_relayer_api::get_relayer_api;
 use get_relayers::get_relayers;
 use pause_relayer::pause_relayer;
 use unpause_relayer::unpause_relayer;
+use update_relay_auto_access_list::update_relay_auto_access_list;
+use update_relay_default_access_list::update_relay_default_access_list;
 use update_relay_eip1559_status::update_relay_eip1559_status;
+use update_relay_envelope_type::update_relay_envelope_type;
 use update_relay_max_gas_price::update_relay_max_gas_price;
+use update_relay_refuse_service::update_relay_refuse_service;
+use update_relay_whitelist_contract::update_relay_whitelist_contract;
 
 pub fn create_relayer_routes() -> Router<Arc<AppState>> {
     Router::new()
@@ -55,4 +68,16 @@ pub fn create_relayer_routes() -> Router<Arc<AppState>> {
         .route("/:relayer_id/allowlists/:address", post(add_allowlist_address))
         .route("/:relayer_id/allowlists/:address", delete(delete_allowlist_address))
         .route("/:relayer_id/gas/eip1559/:enabled", put(update_relay_eip1559_status))
+        .route(
+            "/:relayer_id/whitelist-contract/:address",
+            put(update_relay_whitelist_contract),
+        )
+        .route("/:relayer_id/whitelist-contract", delete(delete_relay_whitelist_contract))
+        .route("/:relayer_id/refuse-service/:enabled", put(update_relay_refuse_service))
+        .route("/:relayer_id/envelope/:preferred_envelope", put(update_relay_envelope_type))
+        .route(
+            "/:relayer_id/envelope-access-list",
+            put(update_relay_default_access_list),
+        )
+        .route("/:relayer_id/auto-access-list/:enabled", put(update_relay_auto_access_list))
 }
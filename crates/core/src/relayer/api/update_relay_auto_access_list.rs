@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::relayer::cache::invalidate_relayer_cache;
+use crate::relayer::get_relayer::relayer_exists;
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, relayer::types::RelayerId};
+
+/// Updates whether a relayer calls `eth_createAccessList` before sending a transaction without
+/// its own access list, keeping the suggested list only when it lowers total gas.
+pub async fn update_relay_auto_access_list(
+    State(state): State<Arc<AppState>>,
+    Path((relayer_id, enabled)): Path<(RelayerId, bool)>,
+) -> Result<StatusCode, HttpError> {
+    let exists = relayer_exists(&state.db, &state.cache, &relayer_id).await?;
+    if exists {
+        state.db.update_relayer_auto_access_list(&relayer_id, &enabled).await?;
+        invalidate_relayer_cache(&state.cache, &relayer_id).await;
+
+        if let Ok(queue) =
+            state.transactions_queues.lock().await.get_transactions_queue_unsafe(&relayer_id)
+        {
+            queue.lock().await.set_auto_access_list(enabled);
+        }
+
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found("Relayer does not exist".to_string()))
+    }
+}
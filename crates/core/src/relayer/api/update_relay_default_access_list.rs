@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use alloy::eips::eip2930::AccessList;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::relayer::cache::invalidate_relayer_cache;
+use crate::relayer::get_relayer::relayer_exists;
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, relayer::types::RelayerId};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateRelayDefaultAccessListRequest {
+    #[serde(rename = "accessList")]
+    pub access_list: Option<AccessList>,
+}
+
+/// Sets the access list attached to outgoing transactions when a relayer's `preferred_envelope`
+/// is `EIP2930`. Has no effect for any other envelope.
+pub async fn update_relay_default_access_list(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+    Json(request): Json<UpdateRelayDefaultAccessListRequest>,
+) -> Result<StatusCode, HttpError> {
+    let exists = relayer_exists(&state.db, &state.cache, &relayer_id).await?;
+    if exists {
+        state
+            .db
+            .update_relayer_default_access_list(&relayer_id, request.access_list.clone())
+            .await?;
+        invalidate_relayer_cache(&state.cache, &relayer_id).await;
+
+        if let Ok(queue) =
+            state.transactions_queues.lock().await.get_transactions_queue_unsafe(&relayer_id)
+        {
+            queue.lock().await.set_default_access_list(request.access_list);
+        }
+
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found("Relayer does not exist".to_string()))
+    }
+}
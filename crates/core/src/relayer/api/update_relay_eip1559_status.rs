@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::relayer::cache::invalidate_relayer_cache;
 use crate::relayer::get_relayer::relayer_exists;
 use crate::shared::{not_found, HttpError};
+use crate::transaction::types::TransactionEnvelopeType;
 use crate::{app_state::AppState, relayer::types::RelayerId};
 use axum::http::HeaderMap;
 use axum::{
@@ -11,6 +12,9 @@ use axum::{
 };
 
 /// Updates the EIP-1559 transaction status for a relayer.
+///
+/// This is a plain legacy/EIP-1559 toggle, so it also pins `preferred_envelope` to one of those
+/// two, overriding any EIP-2930 preference previously set via the envelope endpoint.
 pub async fn update_relay_eip1559_status(
     State(state): State<Arc<AppState>>,
     Path((relayer_id, enabled)): Path<(RelayerId, bool)>,
@@ -19,12 +23,18 @@ pub async fn update_relay_eip1559_status(
     state.validate_basic_auth_valid(&headers)?;
     let exists = relayer_exists(&state.db, &state.cache, &relayer_id).await?;
     if exists {
+        let preferred_envelope =
+            if enabled { TransactionEnvelopeType::EIP1559 } else { TransactionEnvelopeType::LEGACY };
+
         state.db.update_relayer_eip_1559_status(&relayer_id, &enabled).await?;
+        state.db.update_relayer_preferred_envelope(&relayer_id, &preferred_envelope).await?;
         invalidate_relayer_cache(&state.cache, &relayer_id).await;
         if let Ok(queue) =
             state.transactions_queues.lock().await.get_transactions_queue_unsafe(&relayer_id)
         {
-            queue.lock().await.set_is_legacy_transactions(!enabled); // Fixed: EIP-1559 enabled = NOT legacy
+            let mut queue = queue.lock().await;
+            queue.set_is_legacy_transactions(!enabled); // Fixed: EIP-1559 enabled = NOT legacy
+            queue.set_preferred_envelope(preferred_envelope);
         }
 
         Ok(StatusCode::NO_CONTENT)
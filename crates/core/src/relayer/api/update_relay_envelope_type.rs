@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::relayer::cache::invalidate_relayer_cache;
+use crate::relayer::get_relayer::relayer_exists;
+use crate::shared::{not_found, HttpError};
+use crate::transaction::types::TransactionEnvelopeType;
+use crate::{app_state::AppState, relayer::types::RelayerId};
+
+/// Sets the typed-transaction envelope a relayer builds and signs with (legacy, EIP-2930, or
+/// EIP-1559).
+pub async fn update_relay_envelope_type(
+    State(state): State<Arc<AppState>>,
+    Path((relayer_id, preferred_envelope)): Path<(RelayerId, TransactionEnvelopeType)>,
+) -> Result<StatusCode, HttpError> {
+    let exists = relayer_exists(&state.db, &state.cache, &relayer_id).await?;
+    if exists {
+        state.db.update_relayer_preferred_envelope(&relayer_id, &preferred_envelope).await?;
+        invalidate_relayer_cache(&state.cache, &relayer_id).await;
+
+        if let Ok(queue) =
+            state.transactions_queues.lock().await.get_transactions_queue_unsafe(&relayer_id)
+        {
+            queue.lock().await.set_preferred_envelope(preferred_envelope);
+        }
+
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found("Relayer does not exist".to_string()))
+    }
+}
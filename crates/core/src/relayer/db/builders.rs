@@ -13,5 +13,12 @@ pub fn build_relayer(row: &Row) -> Relayer {
         paused: row.get("paused"),
         eip_1559_enabled: row.get("eip_1559_enabled"),
         created_at: row.get("created_at"),
+        whitelist_contract_address: row.get("whitelist_contract_address"),
+        refuse_service: row.get("refuse_service"),
+        preferred_envelope: row.get("preferred_envelope"),
+        default_access_list: row
+            .get::<_, Option<serde_json::Value>>("default_access_list")
+            .and_then(|value| serde_json::from_value(value).ok()),
+        auto_access_list: row.get("auto_access_list"),
     }
 }
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use super::builders::build_relayer;
+use crate::{
+    postgres::{PostgresClient, PostgresError},
+    relayer::types::{Relayer, RelayerId},
+    transaction::queue_system::NodeId,
+};
+
+impl PostgresClient {
+    /// Claims up to `limit` relayers that are either unowned or whose lease has gone stale (the
+    /// owning node crashed without releasing it), assigning them to `node_id`.
+    ///
+    /// Claiming happens at relayer granularity rather than per-transaction: once a relayer is
+    /// claimed here, every transaction this node subsequently loads or sends for it is guaranteed
+    /// not to be touched by any other node, which is what keeps nonce ordering intact. `FOR
+    /// UPDATE SKIP LOCKED` lets a second node running this same query concurrently grab a
+    /// different relayer instead of blocking on the one this node is claiming.
+    ///
+    /// Each claim bumps `lease_epoch`, a fencing token returned alongside the relayer. A node
+    /// holding onto a relayer past its lease (a delayed heartbeat, say) can have it reclaimed by
+    /// another node mid-send; comparing the epoch it captured here against the current value
+    /// before broadcasting lets it detect that and abort instead of racing the new owner.
+    pub async fn claim_relayers_for_node(
+        &self,
+        node_id: &NodeId,
+        lease: Duration,
+        limit: i64,
+    ) -> Result<Vec<(Relayer, i64)>, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    WITH claimable AS (
+                        SELECT id
+                        FROM relayer.record
+                        WHERE deleted = FALSE
+                        AND (locked_by IS NULL OR locked_at < NOW() - make_interval(secs => $3))
+                        ORDER BY id
+                        LIMIT $2
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    UPDATE relayer.record r
+                    SET locked_by = $1, locked_at = NOW(), lease_epoch = r.lease_epoch + 1
+                    FROM claimable
+                    WHERE r.id = claimable.id
+                    RETURNING r.*;
+                ",
+                &[node_id, &limit, &(lease.as_secs() as f64)],
+            )
+            .await?;
+
+        Ok(rows.iter().map(|row| (build_relayer(row), row.get("lease_epoch"))).collect())
+    }
+
+    /// Stamps a brand-new (or just-cloned) relayer as owned by `node_id` from the moment its
+    /// transaction queue starts, so it participates in the same lease-fencing scheme as a relayer
+    /// picked up through `claim_relayers_for_node` instead of starting with no lease at all.
+    pub async fn claim_new_relayer_for_node(
+        &self,
+        relayer_id: &RelayerId,
+        node_id: &NodeId,
+    ) -> Result<i64, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    UPDATE relayer.record
+                    SET locked_by = $1, locked_at = NOW(), lease_epoch = lease_epoch + 1
+                    WHERE id = $2
+                    RETURNING lease_epoch;
+                ",
+                &[node_id, relayer_id],
+            )
+            .await?;
+
+        Ok(rows.first().map(|row| row.get("lease_epoch")).unwrap_or(1))
+    }
+
+    /// Checks whether `node_id` still holds the lease on `relayer_id` at exactly `lease_epoch`,
+    /// i.e. nothing has reclaimed it since this node last claimed it. Called immediately before
+    /// building/broadcasting a transaction so a node whose lease silently expired - and was handed
+    /// to another node by the reaper - aborts instead of sending with a stale nonce manager.
+    pub async fn relayer_lease_is_current(
+        &self,
+        relayer_id: &RelayerId,
+        node_id: &NodeId,
+        lease_epoch: i64,
+    ) -> Result<bool, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    SELECT 1
+                    FROM relayer.record
+                    WHERE id = $1
+                    AND locked_by = $2
+                    AND lease_epoch = $3;
+                ",
+                &[relayer_id, node_id, &lease_epoch],
+            )
+            .await?;
+
+        Ok(!rows.is_empty())
+    }
+
+    /// Refreshes the lease on every relayer `node_id` currently owns, so the reaper doesn't treat
+    /// still-live work as abandoned.
+    pub async fn heartbeat_claimed_relayers(&self, node_id: &NodeId) -> Result<u64, PostgresError> {
+        self.execute(
+            "
+                UPDATE relayer.record
+                SET locked_at = NOW()
+                WHERE locked_by = $1
+                AND deleted = FALSE;
+            ",
+            &[node_id],
+        )
+        .await
+    }
+
+    /// Releases the lease on any relayer whose owning node has gone quiet for longer than
+    /// `lease` - almost always because it crashed - so the next claim pass (on this node or
+    /// another) picks the relayer back up instead of leaving it stranded.
+    pub async fn reclaim_expired_relayer_leases(&self, lease: Duration) -> Result<u64, PostgresError> {
+        self.execute(
+            "
+                UPDATE relayer.record
+                SET locked_by = NULL, locked_at = NULL
+                WHERE locked_by IS NOT NULL
+                AND locked_at < NOW() - make_interval(secs => $1);
+            ",
+            &[&(lease.as_secs() as f64)],
+        )
+        .await
+    }
+}
@@ -1,6 +1,8 @@
 use std::error::Error;
 use thiserror::Error;
 
+use alloy::eips::eip2930::AccessList;
+
 use crate::shared::{internal_server_error, not_found, HttpError};
 use crate::{
     gas::GasPrice,
@@ -8,6 +10,8 @@ use crate::{
     postgres::{PostgresClient, PostgresError},
     provider::EvmProvider,
     relayer::types::{Relayer, RelayerId},
+    shared::common_types::EvmAddress,
+    transaction::types::TransactionEnvelopeType,
 };
 
 #[derive(Error, Debug)]
@@ -75,9 +79,16 @@ impl PostgresClient {
                         CreateRelayerError::WalletError(name.to_string(), *chain_id, Box::new(e))
                     })?;
 
+                let default_access_list = source_relayer
+                    .default_access_list
+                    .as_ref()
+                    .map(|access_list| {
+                        serde_json::to_value(access_list).unwrap_or(serde_json::Value::Null)
+                    });
+
                 self.execute(
-                    "INSERT INTO relayer.record (id, name, chain_id, wallet_index, max_gas_price_cap, paused, eip_1559_enabled, address)
-                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                    "INSERT INTO relayer.record (id, name, chain_id, wallet_index, max_gas_price_cap, paused, eip_1559_enabled, address, whitelist_contract_address, refuse_service, preferred_envelope, default_access_list, auto_access_list)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
                     &[
                         &new_relayer_id,
                         &name,
@@ -87,6 +98,11 @@ impl PostgresClient {
                         &source_relayer.paused,
                         &source_relayer.eip_1559_enabled,
                         &address,
+                        &source_relayer.whitelist_contract_address,
+                        &source_relayer.refuse_service,
+                        &source_relayer.preferred_envelope,
+                        &default_access_list,
+                        &source_relayer.auto_access_list,
                     ],
                 )
                 .await
@@ -99,6 +115,10 @@ impl PostgresClient {
                 let new_relayer_id_val = new_relayer_id;
                 let name_val = name.to_string();
                 let chain_id_val = *chain_id;
+                // The `eip_1559_enabled` column defaults to TRUE, which would otherwise leave a
+                // relayer created on a legacy-only chain trying to broadcast type-2 transactions
+                // regardless of the network's own `supports_eip1559` setting.
+                let supports_eip1559 = evm_provider.supports_eip1559();
 
                 self.with_transaction(move |tx| {
                     Box::pin(async move {
@@ -108,12 +128,18 @@ impl PostgresClient {
                                 FROM relayer.record
                                 WHERE chain_id = $3
                             )
-                            INSERT INTO relayer.record (id, name, chain_id, wallet_index)
-                            SELECT $1, $2, $3, wallet_index
+                            INSERT INTO relayer.record (id, name, chain_id, wallet_index, eip_1559_enabled)
+                            SELECT $1, $2, $3, wallet_index, $4
                             FROM new_wallet_index
                             RETURNING wallet_index";
 
-                        let rows = tx.query(query, &[&new_relayer_id_val, &name_val, &chain_id_val]).await.map_err(PostgresError::PgError)?;
+                        let rows = tx
+                            .query(
+                                query,
+                                &[&new_relayer_id_val, &name_val, &chain_id_val, &supports_eip1559],
+                            )
+                            .await
+                            .map_err(PostgresError::PgError)?;
 
                         let wallet_index: i32 = rows.first()
                             .map(|row| row.get("wallet_index"))
@@ -231,4 +257,103 @@ impl PostgresClient {
 
         Ok(())
     }
+
+    pub async fn update_relayer_whitelist_contract_address(
+        &self,
+        relayer_id: &RelayerId,
+        whitelist_contract_address: Option<EvmAddress>,
+    ) -> Result<(), PostgresError> {
+        let _ = self
+            .execute(
+                "
+                UPDATE relayer.record
+                SET whitelist_contract_address = $1
+                WHERE id = $2
+                ",
+                &[&whitelist_contract_address, relayer_id],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_relayer_refuse_service(
+        &self,
+        relayer_id: &RelayerId,
+        refuse_service: &bool,
+    ) -> Result<(), PostgresError> {
+        let _ = self
+            .execute(
+                "
+                UPDATE relayer.record
+                SET refuse_service = $1
+                WHERE id = $2
+                ",
+                &[refuse_service, relayer_id],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_relayer_preferred_envelope(
+        &self,
+        relayer_id: &RelayerId,
+        preferred_envelope: &TransactionEnvelopeType,
+    ) -> Result<(), PostgresError> {
+        let _ = self
+            .execute(
+                "
+                UPDATE relayer.record
+                SET preferred_envelope = $1
+                WHERE id = $2
+                ",
+                &[preferred_envelope, relayer_id],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_relayer_default_access_list(
+        &self,
+        relayer_id: &RelayerId,
+        default_access_list: Option<AccessList>,
+    ) -> Result<(), PostgresError> {
+        let default_access_list = default_access_list
+            .as_ref()
+            .map(|access_list| serde_json::to_value(access_list).unwrap_or(serde_json::Value::Null));
+
+        let _ = self
+            .execute(
+                "
+                UPDATE relayer.record
+                SET default_access_list = $1
+                WHERE id = $2
+                ",
+                &[&default_access_list, relayer_id],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_relayer_auto_access_list(
+        &self,
+        relayer_id: &RelayerId,
+        auto_access_list: &bool,
+    ) -> Result<(), PostgresError> {
+        let _ = self
+            .execute(
+                "
+                UPDATE relayer.record
+                SET auto_access_list = $1
+                WHERE id = $2
+                ",
+                &[auto_access_list, relayer_id],
+            )
+            .await?;
+
+        Ok(())
+    }
 }
@@ -14,3 +14,6 @@ pub use db::{CreateRelayerError, CreateRelayerMode};
 
 mod get_relayer;
 pub use get_relayer::{get_relayer, get_relayer_provider_context_by_relayer_id, relayer_exists};
+
+mod onchain_allowlist;
+pub use onchain_allowlist::{OnchainAllowlistCache, OnchainAllowlistError};
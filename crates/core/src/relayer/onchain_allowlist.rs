@@ -0,0 +1,110 @@
+use std::{collections::HashMap, time::Duration};
+
+use alloy::rpc::types::serde_helpers::WithOtherFields;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use thiserror::Error;
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::{provider::RelayerProvider, shared::common_types::EvmAddress};
+
+/// How long a `certified` lookup is trusted before the contract is queried again.
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+sol! {
+    #[sol(rpc)]
+    interface IOnchainAllowlist {
+        function certified(address account) external view returns (bool);
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum OnchainAllowlistError {
+    #[error("Failed to call OnchainAllowlist.certified: {0}")]
+    CallFailed(String),
+
+    #[error("Failed to decode OnchainAllowlist.certified response: {0}")]
+    DecodeFailed(String),
+}
+
+struct CacheEntry {
+    certified: bool,
+    fetched_at: Instant,
+}
+
+/// Caches `certified(address)` lookups against a relayer's on-chain allowlist contract, so a
+/// relayer with `refuse_service` enabled doesn't issue an RPC call for every transaction it sends.
+/// Entries are refreshed lazily - there's no block subscription in this codebase to invalidate on,
+/// so a short TTL is used instead.
+pub struct OnchainAllowlistCache {
+    entries: Mutex<HashMap<(EvmAddress, EvmAddress), CacheEntry>>,
+}
+
+impl OnchainAllowlistCache {
+    pub fn new() -> Self {
+        Self { entries: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns whether `address` is certified by the allowlist contract at `contract_address`,
+    /// consulting the cache first and only falling through to an RPC call once the cached entry
+    /// has expired (or doesn't exist yet).
+    pub async fn is_certified(
+        &self,
+        provider: &RelayerProvider,
+        contract_address: &EvmAddress,
+        address: &EvmAddress,
+    ) -> Result<bool, OnchainAllowlistError> {
+        let key = (*contract_address, *address);
+
+        {
+            let entries = self.entries.lock().await;
+            if let Some(entry) = entries.get(&key) {
+                if entry.fetched_at.elapsed() < CACHE_TTL {
+                    return Ok(entry.certified);
+                }
+            }
+        }
+
+        let certified = Self::fetch_certified(provider, contract_address, address).await?;
+
+        let mut entries = self.entries.lock().await;
+        entries.insert(key, CacheEntry { certified, fetched_at: Instant::now() });
+
+        Ok(certified)
+    }
+
+    async fn fetch_certified(
+        provider: &RelayerProvider,
+        contract_address: &EvmAddress,
+        address: &EvmAddress,
+    ) -> Result<bool, OnchainAllowlistError> {
+        let call = IOnchainAllowlist::certifiedCall { account: address.into_address() };
+
+        let call_tx = WithOtherFields::new(alloy::rpc::types::TransactionRequest {
+            to: Some(alloy::primitives::TxKind::Call(contract_address.into_address())),
+            input: Some(call.abi_encode().into()).into(),
+            ..Default::default()
+        });
+
+        let result = provider
+            .call(&call_tx)
+            .await
+            .map_err(|e| OnchainAllowlistError::CallFailed(e.to_string()))?;
+
+        let decoded = IOnchainAllowlist::certifiedCall::abi_decode_returns(&result, false)
+            .map_err(|e| OnchainAllowlistError::DecodeFailed(e.to_string()))?;
+
+        Ok(decoded._0)
+    }
+
+    /// Drops every cached entry, forcing the next lookup for any address to hit the chain again.
+    pub async fn invalidate_all(&self) {
+        self.entries.lock().await.clear();
+    }
+}
+
+impl Default for OnchainAllowlistCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
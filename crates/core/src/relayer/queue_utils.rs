@@ -6,7 +6,10 @@ use crate::{
     provider::EvmProvider,
     relayer::{cache::invalidate_relayer_cache, Relayer},
     shared::HttpError,
-    transaction::{queue_system::TransactionsQueueSetup, NonceManager},
+    transaction::{
+        queue_system::{NonceCap, TransactionsQueueSetup},
+        NonceManager,
+    },
 };
 
 /// Starts the transaction queue for a relayer and initializes it with the current nonce.
@@ -28,8 +31,18 @@ pub async fn start_relayer_queue(
     let max_gas_price_multiplier =
         network_config.map(|config| config.max_gas_price_multiplier).unwrap_or(2);
 
+    let per_relayer_max_inflight =
+        network_config.map(|config| config.per_relayer_max_inflight).unwrap_or(1000);
+
+    let nonce_cap = network_config
+        .and_then(|config| config.max_future_nonces)
+        .map(NonceCap::new)
+        .unwrap_or_default();
+
     let relayer_id = relayer.id;
 
+    let lease_epoch = state.db.claim_new_relayer_for_node(&relayer_id, &state.node_id).await?;
+
     // Start the transaction queue for this relayer
     state
         .transactions_queues
@@ -46,6 +59,10 @@ pub async fn start_relayer_queue(
                 state.safe_proxy_manager.clone(),
                 gas_bump_config,
                 max_gas_price_multiplier,
+                nonce_cap,
+                per_relayer_max_inflight,
+                state.node_id,
+                lease_epoch,
             ),
             state.transactions_queues.clone(),
         )
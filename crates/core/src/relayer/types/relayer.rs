@@ -1,9 +1,16 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use alloy::eips::eip2930::AccessList;
+
 use super::{RelayerId, WalletIndex};
 use crate::wallet::{WalletManagerChainId, WalletManagerCloneChain};
-use crate::{gas::GasPrice, network::ChainId, shared::common_types::EvmAddress};
+use crate::{
+    gas::GasPrice,
+    network::ChainId,
+    shared::common_types::EvmAddress,
+    transaction::types::TransactionEnvelopeType,
+};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Relayer {
@@ -45,6 +52,32 @@ pub struct Relayer {
     /// Whether this relayer uses a private key (vs mnemonic-derived)
     #[serde(rename = "isPrivateKey")]
     pub is_private_key: bool,
+
+    /// On-chain allowlist contract consulted when `refuse_service` is enabled. Exposes a
+    /// `certified(address) -> bool` view function that gates which recipients this relayer will
+    /// send transactions to.
+    #[serde(rename = "whitelistContractAddress", skip_serializing_if = "Option::is_none", default)]
+    pub whitelist_contract_address: Option<EvmAddress>,
+
+    /// If true, the relayer refuses to send a transaction unless its `to` address is certified by
+    /// `whitelist_contract_address`. Has no effect while `whitelist_contract_address` is unset.
+    #[serde(rename = "refuseService", default)]
+    pub refuse_service: bool,
+
+    /// The typed-transaction envelope this relayer builds and signs with. Generalizes
+    /// `eip_1559_enabled`, adding an EIP-2930 access-list option alongside legacy and EIP-1559.
+    #[serde(rename = "preferredEnvelope")]
+    pub preferred_envelope: TransactionEnvelopeType,
+
+    /// Access list attached to outgoing transactions when `preferred_envelope` is `EIP2930`.
+    /// Unused for any other envelope.
+    #[serde(rename = "defaultAccessList", skip_serializing_if = "Option::is_none", default)]
+    pub default_access_list: Option<AccessList>,
+
+    /// If true, a transaction without its own access list is sent through `eth_createAccessList`
+    /// before broadcast, and the suggested list is kept only when it lowers total gas.
+    #[serde(rename = "autoAccessList", default)]
+    pub auto_access_list: bool,
 }
 
 impl Relayer {
@@ -1,11 +1,42 @@
 use crate::{
     postgres::{PostgresClient, PostgresError},
-    schema::v1_0_0::apply_v1_0_0_schema,
+    schema::{
+        v1_0_0::apply_v1_0_0_schema, v1_0_1::apply_v1_0_1_schema, v1_0_2::apply_v1_0_2_schema,
+        v1_0_3::apply_v1_0_3_schema, v1_0_4::apply_v1_0_4_schema, v1_0_5::apply_v1_0_5_schema,
+        v1_0_6::apply_v1_0_6_schema, v1_0_7::apply_v1_0_7_schema, v1_0_8::apply_v1_0_8_schema,
+        v1_0_9::apply_v1_0_9_schema, v1_0_10::apply_v1_0_10_schema,
+        v1_0_11::apply_v1_0_11_schema, v1_0_12::apply_v1_0_12_schema,
+    },
 };
 
 mod v1_0_0;
+mod v1_0_1;
+mod v1_0_2;
+mod v1_0_3;
+mod v1_0_4;
+mod v1_0_5;
+mod v1_0_6;
+mod v1_0_7;
+mod v1_0_8;
+mod v1_0_9;
+mod v1_0_10;
+mod v1_0_11;
+mod v1_0_12;
 
-/// Applies the database schema to the database.
+/// Applies the database schema to the database, in version order.
 pub async fn apply_schema(client: &PostgresClient) -> Result<(), PostgresError> {
-    apply_v1_0_0_schema(client).await
+    apply_v1_0_0_schema(client).await?;
+    apply_v1_0_1_schema(client).await?;
+    apply_v1_0_2_schema(client).await?;
+    apply_v1_0_3_schema(client).await?;
+    apply_v1_0_4_schema(client).await?;
+    apply_v1_0_5_schema(client).await?;
+    apply_v1_0_6_schema(client).await?;
+    apply_v1_0_7_schema(client).await?;
+    apply_v1_0_8_schema(client).await?;
+    apply_v1_0_9_schema(client).await?;
+    apply_v1_0_10_schema(client).await?;
+    apply_v1_0_11_schema(client).await?;
+    apply_v1_0_12_schema(client).await?;
+    Ok(())
 }
@@ -0,0 +1,53 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.10.
+/// Adds `relayer.archived_transaction`, a cold-storage mirror of `relayer.transaction` that the
+/// retention subsystem moves finished transactions into, keeping the hot table - and everything
+/// that gets loaded from it at startup - small on long-running relayers.
+pub async fn apply_v1_0_10_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        CREATE TABLE IF NOT EXISTS relayer.archived_transaction (
+            id UUID PRIMARY KEY NOT NULL,
+            relayer_id UUID NOT NULL,
+            "to" BYTEA NOT NULL,
+            "from" BYTEA NOT NULL,
+            nonce BIGINT NOT NULL,
+            data BYTEA NULL,
+            value NUMERIC(80) NOT NULL,
+            chain_id BIGINT NOT NULL,
+            gas_price NUMERIC NULL,
+            sent_max_priority_fee_per_gas NUMERIC(80) NULL,
+            sent_max_fee_per_gas NUMERIC(80) NULL,
+            gas_limit NUMERIC(80) NULL,
+            block_hash BYTEA NULL,
+            block_number BIGINT NULL,
+            hash BYTEA NULL,
+            speed relayer.speed NOT NULL,
+            status relayer.tx_status NOT NULL,
+            blobs BYTEA[] NULL,
+            expires_at TIMESTAMPTZ NOT NULL,
+            expired_at TIMESTAMPTZ NULL,
+            queued_at TIMESTAMPTZ NOT NULL,
+            mined_at TIMESTAMPTZ NULL,
+            failed_at TIMESTAMPTZ NULL,
+            failed_reason TEXT NULL,
+            sent_at TIMESTAMPTZ NULL,
+            confirmed_at TIMESTAMPTZ NULL,
+            external_id VARCHAR(255) NULL,
+            resubmission_count INT NOT NULL DEFAULT 0,
+            max_fee_cap NUMERIC(80) NULL,
+            max_resubmissions INT NULL,
+            gas_used NUMERIC(80) NULL,
+            effective_gas_price NUMERIC(80) NULL,
+            reverted BOOLEAN NULL,
+            relayed BOOLEAN NOT NULL DEFAULT FALSE,
+            archived_at TIMESTAMPTZ DEFAULT NOW() NOT NULL
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_archived_transaction_relayer_queued_at
+        ON relayer.archived_transaction(relayer_id, queued_at DESC);
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.11.
+/// Adds an `auto_access_list` toggle, opting a relayer into calling `eth_createAccessList`
+/// before sending and keeping the suggested list only when it lowers total gas.
+pub async fn apply_v1_0_11_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS auto_access_list BOOLEAN NOT NULL DEFAULT FALSE;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.12.
+/// Adds a `lease_epoch` fencing token to `relayer.record`, bumped every time a relayer is
+/// (re-)claimed by `claim_relayers_for_node`. A node holding a stale lease (e.g. after a missed
+/// heartbeat let the reaper hand the relayer to another node) can compare its captured epoch
+/// against the current value before broadcasting and abort instead of racing the new owner.
+pub async fn apply_v1_0_12_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS lease_epoch BIGINT NOT NULL DEFAULT 0;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
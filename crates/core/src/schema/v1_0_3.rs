@@ -0,0 +1,23 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.3.
+/// Adds per-transaction fee-escalation tracking (resubmission count, max fee cap, max attempts)
+/// and the `FEECAPPED` terminal status surfaced when the escalator's ceiling is hit.
+pub async fn apply_v1_0_3_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.transaction
+        ADD COLUMN IF NOT EXISTS resubmission_count INT NOT NULL DEFAULT 0,
+        ADD COLUMN IF NOT EXISTS max_fee_cap NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS max_resubmissions INT NULL;
+
+        ALTER TABLE relayer.transaction_audit_log
+        ADD COLUMN IF NOT EXISTS resubmission_count INT NOT NULL DEFAULT 0,
+        ADD COLUMN IF NOT EXISTS max_fee_cap NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS max_resubmissions INT NULL;
+
+        ALTER TYPE relayer.tx_status ADD VALUE IF NOT EXISTS 'FEECAPPED';
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.4.
+/// Adds dedicated columns for the gas actually used, the effective gas price paid, and whether
+/// the transaction reverted, read from the mining receipt. These used to be folded into (and
+/// silently overwrite) `gas_limit`; this migration gives them their own storage instead.
+pub async fn apply_v1_0_4_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.transaction
+        ADD COLUMN IF NOT EXISTS gas_used NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS effective_gas_price NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS reverted BOOLEAN NULL;
+
+        ALTER TABLE relayer.transaction_audit_log
+        ADD COLUMN IF NOT EXISTS gas_used NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS effective_gas_price NUMERIC(80) NULL,
+        ADD COLUMN IF NOT EXISTS reverted BOOLEAN NULL;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.5.
+/// Adds an optional on-chain allowlist contract address per relayer, and a `refuse_service` flag
+/// that, when set, makes the relayer reject any transaction whose recipient isn't certified by
+/// that contract.
+pub async fn apply_v1_0_5_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS whitelist_contract_address BYTEA NULL,
+        ADD COLUMN IF NOT EXISTS refuse_service BOOLEAN NOT NULL DEFAULT FALSE;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.6.
+/// Adds the `relayed` origin flag, marking transactions that came in through an L1 forced-inclusion
+/// event rather than this relayer's normal send path, and the `RELAYEDFAILED` status used when a
+/// relayed transaction is rejected, reverted, or otherwise fails to land.
+pub async fn apply_v1_0_6_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.transaction
+        ADD COLUMN IF NOT EXISTS relayed BOOLEAN NOT NULL DEFAULT FALSE;
+
+        ALTER TABLE relayer.transaction_audit_log
+        ADD COLUMN IF NOT EXISTS relayed BOOLEAN NOT NULL DEFAULT FALSE;
+
+        ALTER TYPE relayer.tx_status ADD VALUE IF NOT EXISTS 'RELAYEDFAILED';
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
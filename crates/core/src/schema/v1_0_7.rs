@@ -0,0 +1,30 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.7.
+/// Generalizes a relayer's plain `eip_1559_enabled` boolean into a `preferred_envelope` enum
+/// (legacy, EIP-2930 access-list, or EIP-1559) and adds a `default_access_list` column attached
+/// to outgoing transactions when the relayer is pinned to EIP-2930.
+pub async fn apply_v1_0_7_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        DO $$
+        BEGIN
+            IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'tx_envelope_type' AND typtype = 'e') THEN
+                CREATE TYPE relayer.tx_envelope_type AS ENUM ('LEGACY', 'EIP2930', 'EIP1559');
+            END IF;
+        END;
+        $$;
+
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS preferred_envelope relayer.tx_envelope_type NOT NULL DEFAULT 'EIP1559';
+
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS default_access_list JSONB NULL;
+
+        UPDATE relayer.record
+        SET preferred_envelope = 'LEGACY'
+        WHERE eip_1559_enabled = FALSE;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.8.
+/// Adds `locked_by`/`locked_at` ownership columns to `relayer.record` and `relayer.transaction`
+/// so several rrelayer processes can share the same database: a relayer (and, by extension, its
+/// transaction queue) is claimed by exactly one node at a time via `SELECT ... FOR UPDATE SKIP
+/// LOCKED`, with the lease refreshed by a heartbeat and released by a reaper if the owning node
+/// stops renewing it.
+pub async fn apply_v1_0_8_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        ALTER TABLE relayer.record
+        ADD COLUMN IF NOT EXISTS locked_by UUID NULL,
+        ADD COLUMN IF NOT EXISTS locked_at TIMESTAMPTZ NULL;
+
+        ALTER TABLE relayer.transaction
+        ADD COLUMN IF NOT EXISTS locked_by UUID NULL,
+        ADD COLUMN IF NOT EXISTS locked_at TIMESTAMPTZ NULL;
+
+        CREATE INDEX IF NOT EXISTS idx_relayer_record_locked_at
+        ON relayer.record(locked_at) WHERE locked_by IS NOT NULL;
+
+        CREATE INDEX IF NOT EXISTS idx_relayer_transaction_locked_at
+        ON relayer.transaction(locked_at) WHERE locked_by IS NOT NULL;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::postgres::{PostgresClient, PostgresError};
+
+/// Applies the RRelayer database schema version 1.0.9.
+/// Adds `relayer.scheduled_transaction`, which lets a relayer be given transactions to send on a
+/// future or recurring schedule (a `period_in_seconds`) rather than only on demand, for things
+/// like keep-alive pings, periodic oracle updates, or subscription settlements.
+pub async fn apply_v1_0_9_schema(client: &PostgresClient) -> Result<(), PostgresError> {
+    let schema_sql = r#"
+        CREATE TABLE IF NOT EXISTS relayer.scheduled_transaction (
+            id UUID PRIMARY KEY NOT NULL,
+            relayer_id UUID NOT NULL,
+            "to" BYTEA NOT NULL,
+            value NUMERIC(80) NOT NULL,
+            data BYTEA NULL,
+            speed relayer.speed NOT NULL,
+            external_id VARCHAR(255) NULL,
+            period_in_seconds BIGINT NULL,
+            next_run_at TIMESTAMPTZ NOT NULL,
+            cancelled BOOLEAN NOT NULL DEFAULT FALSE,
+            created_at TIMESTAMPTZ DEFAULT NOW() NOT NULL,
+            CONSTRAINT fk_relayer_scheduled_transaction_relayer_id
+               FOREIGN KEY (relayer_id)
+                   REFERENCES relayer.record (id)
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_relayer_scheduled_transaction_due
+        ON relayer.scheduled_transaction(relayer_id, next_run_at)
+        WHERE cancelled = FALSE;
+    "#;
+
+    client.batch_execute(schema_sql).await?;
+    Ok(())
+}
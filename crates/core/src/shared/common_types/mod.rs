@@ -2,7 +2,10 @@ mod evm_address;
 pub use evm_address::EvmAddress;
 
 mod paging;
-pub use paging::{PagingContext, PagingQuery, PagingResult};
+pub use paging::{
+    CursorPagingContext, CursorPagingQuery, CursorPagingResult, PagingContext, PagingQuery,
+    PagingResult,
+};
 
 mod block_hash;
 pub use block_hash::BlockHash;
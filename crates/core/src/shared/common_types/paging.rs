@@ -54,3 +54,41 @@ pub struct PagingQuery {
     pub limit: u32,
     pub offset: u32,
 }
+
+/// Keyset (cursor) pagination context, used instead of `PagingContext`'s offset/limit where
+/// `OFFSET` would degrade or skip/duplicate rows under concurrent inserts (e.g. user listing).
+/// `cursor` is the opaque value returned as `next_cursor` by the previous page; `None` means the
+/// first page.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CursorPagingContext {
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+impl CursorPagingContext {
+    pub fn new(cursor: Option<String>, limit: u32) -> Self {
+        CursorPagingContext { cursor, limit }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CursorPagingResult<TResult: Serialize> {
+    pub items: Vec<TResult>,
+
+    /// Opaque cursor to pass as `cursor` on the next request. `None` means there is no further
+    /// page.
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+impl<TResult: Serialize> CursorPagingResult<TResult> {
+    pub fn new(items: Vec<TResult>, next_cursor: Option<String>) -> Self {
+        CursorPagingResult { items, next_cursor }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CursorPagingQuery {
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
@@ -5,7 +5,7 @@ use crate::common_types::EvmAddress;
 use crate::gas::{BlobGasOracleCache, GasOracleCache};
 use crate::network::{create_network_routes, ChainId};
 use crate::shared::HttpError;
-use crate::webhooks::WebhookManager;
+use crate::webhooks::{create_webhooks_routes, WebhookManager};
 use crate::yaml::{ApiKey, NetworkPermissionsConfig, ReadYamlError};
 use crate::{
     app_state::AppState,
@@ -22,7 +22,8 @@ use crate::{
     transaction::{
         api::create_transactions_routes,
         queue_system::{
-            startup_transactions_queues, StartTransactionsQueuesError, TransactionsQueues,
+            startup_transactions_queues, NodeId, RelayerTaskRegistry, StartTransactionsQueuesError,
+            TransactionsQueues,
         },
     },
     ApiConfig, RateLimitConfig, SafeProxyConfig, SetupConfig,
@@ -40,6 +41,7 @@ use dotenv::dotenv;
 use std::path::Path;
 use std::{net::SocketAddr, sync::Arc, time::Instant};
 use thiserror::Error;
+use tokio::signal;
 use tokio::sync::Mutex;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 use tracing::{error, info};
@@ -149,6 +151,33 @@ async fn activity_logger(req: Request<Body>, next: Next) -> Result<Response, Sta
     }
 }
 
+/// Waits for Ctrl+C or (on unix) SIGTERM, then cancels every relayer's processing tasks and
+/// awaits their handles so in-flight database transactions complete before the process exits.
+async fn shutdown_signal(relayer_task_registry: RelayerTaskRegistry) {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("failed to install Ctrl+C signal handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM signal handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    info!("Shutdown signal received, stopping relayer processing tasks");
+    relayer_task_registry.shutdown().await;
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn start_api(
     api_config: ApiConfig,
@@ -165,6 +194,8 @@ async fn start_api(
     db: Arc<PostgresClient>,
     safe_proxy_manager: Arc<SafeProxyManager>,
     relayer_internal_only: RelayersInternalOnly,
+    relayer_task_registry: RelayerTaskRegistry,
+    node_id: NodeId,
     config: &SetupConfig,
 ) -> Result<(), StartApiError> {
     // Calculate which networks are configured with only private keys
@@ -216,6 +247,7 @@ async fn start_api(
         api_keys: Arc::new(api_keys),
         network_configs: Arc::new(config.networks.clone()),
         private_key_only_networks: Arc::new(private_key_only_networks),
+        node_id,
     });
 
     let cors = CorsLayer::new()
@@ -242,7 +274,8 @@ async fn start_api(
         .nest("/networks", create_network_routes())
         .nest("/relayers", create_relayer_routes())
         .nest("/transactions", create_transactions_routes())
-        .nest("/signing", create_signing_routes());
+        .nest("/signing", create_signing_routes())
+        .nest("/webhooks", create_webhooks_routes());
 
     let app = Router::new()
         .route("/health", get(health_check))
@@ -258,7 +291,10 @@ async fn start_api(
 
     let listener = tokio::net::TcpListener::bind(&address).await?;
     info!("rrelayer is up on http://{}", address);
-    axum::serve(listener, app).await.map_err(StartApiError::ApiStartupError)?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(relayer_task_registry))
+        .await
+        .map_err(StartApiError::ApiStartupError)?;
 
     Ok(())
 }
@@ -375,7 +411,7 @@ pub async fn start(project_path: &Path) -> Result<(), StartError> {
     let safe_proxy_manager = Arc::new(SafeProxyManager::new(safe_configs));
     let relayer_internal_only = RelayersInternalOnly::new(relayer_internal_only);
 
-    let transaction_queue = startup_transactions_queues(
+    let (transaction_queue, relayer_task_registry, node_id) = startup_transactions_queues(
         gas_oracle_cache.clone(),
         blob_gas_oracle_cache.clone(),
         providers.clone(),
@@ -384,6 +420,7 @@ pub async fn start(project_path: &Path) -> Result<(), StartError> {
         safe_proxy_manager.clone(),
         Arc::new(config.networks.clone()),
         config.signing_provider.clone().map(Arc::new),
+        config.transaction_retention.clone(),
     )
     .await?;
 
@@ -425,6 +462,8 @@ pub async fn start(project_path: &Path) -> Result<(), StartError> {
         postgres_client,
         safe_proxy_manager,
         relayer_internal_only,
+        relayer_task_registry,
+        node_id,
         &config,
     )
     .await?;
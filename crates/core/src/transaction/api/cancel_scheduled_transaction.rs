@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
+
+use crate::{
+    app_state::AppState,
+    shared::{not_found, HttpError},
+    transaction::types::ScheduledTransactionId,
+};
+
+/// API endpoint to cancel a scheduled transaction job so it no longer fires.
+pub async fn cancel_scheduled_transaction(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<ScheduledTransactionId>,
+    headers: HeaderMap,
+) -> Result<StatusCode, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let cancelled = state.db.cancel_scheduled_transaction(&id).await?;
+    if cancelled {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found("Scheduled transaction does not exist or is already cancelled".to_string()))
+    }
+}
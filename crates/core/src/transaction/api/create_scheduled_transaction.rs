@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use super::types::TransactionSpeed;
+use crate::{
+    app_state::AppState,
+    relayer::{get_relayer, RelayerId},
+    shared::{
+        common_types::EvmAddress, not_found, serializers::deserialize_system_time_option,
+        HttpError,
+    },
+    transaction::types::{
+        ScheduledTransaction, ScheduledTransactionId, TransactionData, TransactionValue,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateScheduledTransactionRequest {
+    pub to: EvmAddress,
+    #[serde(default)]
+    pub value: TransactionValue,
+    #[serde(default)]
+    pub data: TransactionData,
+    pub speed: Option<TransactionSpeed>,
+    #[serde(rename = "externalId", skip_serializing_if = "Option::is_none", default)]
+    pub external_id: Option<String>,
+    /// How often the job repeats once it first fires. Omitting this makes the job a one-off.
+    #[serde(rename = "periodInSeconds", skip_serializing_if = "Option::is_none", default)]
+    pub period_in_seconds: Option<i64>,
+    /// When the job should first fire. Defaults to now, so a recurring job with no `scheduledAt`
+    /// starts its first interval immediately.
+    #[serde(
+        rename = "scheduledAt",
+        deserialize_with = "deserialize_system_time_option",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub scheduled_at: Option<SystemTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateScheduledTransactionResult {
+    pub id: ScheduledTransactionId,
+}
+
+/// API endpoint to register a transaction to fire once at a future time, or repeatedly on a
+/// fixed interval.
+pub async fn create_scheduled_transaction(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+    headers: HeaderMap,
+    Json(request): Json<CreateScheduledTransactionRequest>,
+) -> Result<Json<CreateScheduledTransactionResult>, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    get_relayer(&state.db, &state.cache, &relayer_id)
+        .await?
+        .ok_or(not_found("Relayer does not exist".to_string()))?;
+
+    let scheduled_transaction = ScheduledTransaction {
+        id: ScheduledTransactionId::new(),
+        relayer_id,
+        to: request.to,
+        value: request.value,
+        data: request.data,
+        speed: request.speed.unwrap_or(TransactionSpeed::FAST),
+        external_id: request.external_id,
+        period_in_seconds: request.period_in_seconds,
+        next_run_at: request.scheduled_at.unwrap_or_else(SystemTime::now),
+        cancelled: false,
+        created_at: None,
+    };
+
+    state.db.create_scheduled_transaction(&scheduled_transaction).await?;
+
+    Ok(Json(CreateScheduledTransactionResult { id: scheduled_transaction.id }))
+}
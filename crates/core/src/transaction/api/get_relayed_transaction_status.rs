@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::{not_found, HttpError};
+use crate::{
+    app_state::AppState,
+    shared::common_types::BlockNumber,
+    transaction::{
+        get_relayed_transaction_by_id,
+        types::{TransactionId, TransactionStatus},
+    },
+};
+
+/// Result of querying a relayed (forced-inclusion) transaction's status.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelayedTransactionStatusResult {
+    pub status: TransactionStatus,
+    #[serde(rename = "blockNumber")]
+    pub block_number: Option<BlockNumber>,
+    #[serde(rename = "failedReason")]
+    pub failed_reason: Option<String>,
+}
+
+/// API endpoint to retrieve the status of a relayed (forced-inclusion) transaction - whether it
+/// was accepted, reverted, or dropped - with the failure reason persisted when it didn't land.
+pub async fn get_relayed_transaction_status(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<TransactionId>,
+    headers: HeaderMap,
+) -> Result<Json<RelayedTransactionStatusResult>, HttpError> {
+    state.validate_allowed_passed_basic_auth(&headers)?;
+
+    let transaction = get_relayed_transaction_by_id(&state.cache, &state.db, id)
+        .await?
+        .ok_or(not_found("Relayed transaction id not found".to_string()))?;
+
+    state.validate_auth_basic_or_api_key(&headers, &transaction.from, &transaction.chain_id)?;
+
+    Ok(Json(RelayedTransactionStatusResult {
+        status: transaction.status,
+        block_number: transaction.block_number,
+        failed_reason: transaction.failed_reason,
+    }))
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    Json,
+};
+
+use crate::{
+    app_state::AppState,
+    relayer::RelayerId,
+    shared::{
+        common_types::{PagingContext, PagingQuery, PagingResult},
+        HttpError,
+    },
+    transaction::types::ScheduledTransaction,
+};
+
+/// API endpoint to list the scheduled transaction jobs registered for a relayer.
+pub async fn get_scheduled_transactions(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+    Query(paging): Query<PagingQuery>,
+    headers: HeaderMap,
+) -> Result<Json<PagingResult<ScheduledTransaction>>, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let paging_context = PagingContext::new(paging.limit, paging.offset);
+
+    let result =
+        state.db.get_scheduled_transactions_for_relayer(&relayer_id, &paging_context).await?;
+
+    Ok(Json(result))
+}
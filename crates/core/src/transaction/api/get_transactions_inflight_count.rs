@@ -0,0 +1,27 @@
+use crate::relayer::get_relayer;
+use crate::shared::{not_found, HttpError};
+use crate::{app_state::AppState, relayer::RelayerId};
+use axum::http::HeaderMap;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use std::sync::Arc;
+
+/// API endpoint to get the combined pending + inmempool transaction count for a relayer.
+pub async fn get_transactions_inflight_count(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+    headers: HeaderMap,
+) -> Result<Json<usize>, HttpError> {
+    let relayer = get_relayer(&state.db, &state.cache, &relayer_id)
+        .await?
+        .ok_or(not_found("Relayer could not be found".to_string()))?;
+
+    state.validate_auth_basic_or_api_key(&headers, &relayer.address, &relayer.chain_id)?;
+
+    let count =
+        state.transactions_queues.lock().await.inflight_transactions_count(&relayer_id).await;
+
+    Ok(Json(count))
+}
@@ -7,15 +7,27 @@ use axum::{
 
 use crate::app_state::AppState;
 
+mod cancel_scheduled_transaction;
 mod cancel_transaction;
 pub use cancel_transaction::CancelTransactionResponse;
+mod create_scheduled_transaction;
+pub use create_scheduled_transaction::{
+    CreateScheduledTransactionRequest, CreateScheduledTransactionResult,
+};
+mod get_archived_transactions;
+mod get_relayed_transaction_status;
+pub use get_relayed_transaction_status::RelayedTransactionStatusResult;
 mod get_relayer_transactions;
+mod get_scheduled_transactions;
 mod get_transaction_by_id;
 mod get_transaction_status;
 pub use get_transaction_status::RelayTransactionStatusResult;
+mod get_transactions_inflight_count;
 mod get_transactions_inmempool_count;
 mod get_transactions_pending_count;
 mod replace_transaction;
+mod send_batch_transactions;
+pub use send_batch_transactions::{SendBatchTransactionsRequest, SendBatchTransactionsResult};
 mod send_transaction;
 pub use send_transaction::{RelayTransactionRequest, SendTransactionResult};
 mod send_random_transaction;
@@ -27,7 +39,15 @@ pub fn create_transactions_routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/:id", get(get_transaction_by_id::get_transaction_by_id_api))
         .route("/status/:id", get(get_transaction_status::get_transaction_status))
+        .route(
+            "/relayed/status/:id",
+            get(get_relayed_transaction_status::get_relayed_transaction_status),
+        )
         .route("/relayers/:relayer_id/send", post(send_transaction::handle_send_transaction))
+        .route(
+            "/relayers/:relayer_id/send/batch",
+            post(send_batch_transactions::handle_send_batch_transactions),
+        )
         .route(
             "/relayers/:chain_id/send_random",
             post(send_random_transaction::send_random_transaction),
@@ -35,6 +55,10 @@ pub fn create_transactions_routes() -> Router<Arc<AppState>> {
         .route("/replace/:transaction_id", put(replace_transaction::replace_transaction))
         .route("/cancel/:transaction_id", put(cancel_transaction::cancel_transaction))
         .route("/relayers/:relayer_id", get(get_relayer_transactions::get_relayer_transactions))
+        .route(
+            "/relayers/:relayer_id/archived",
+            get(get_archived_transactions::get_archived_transactions),
+        )
         .route(
             "/relayers/:relayer_id/pending/count",
             get(get_transactions_pending_count::get_transactions_pending_count),
@@ -43,4 +67,20 @@ pub fn create_transactions_routes() -> Router<Arc<AppState>> {
             "/relayers/:relayer_id/inmempool/count",
             get(get_transactions_inmempool_count::get_transactions_inmempool_count),
         )
+        .route(
+            "/relayers/:relayer_id/inflight/count",
+            get(get_transactions_inflight_count::get_transactions_inflight_count),
+        )
+        .route(
+            "/relayers/:relayer_id/scheduled",
+            post(create_scheduled_transaction::create_scheduled_transaction),
+        )
+        .route(
+            "/relayers/:relayer_id/scheduled",
+            get(get_scheduled_transactions::get_scheduled_transactions),
+        )
+        .route(
+            "/scheduled/cancel/:id",
+            put(cancel_scheduled_transaction::cancel_scheduled_transaction),
+        )
 }
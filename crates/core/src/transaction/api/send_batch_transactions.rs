@@ -0,0 +1,142 @@
+use crate::rate_limiting::RateLimiter;
+use crate::relayer::{get_relayer, Relayer};
+use crate::shared::utils::convert_blob_strings_to_blobs;
+use crate::shared::{bad_request, internal_server_error, not_found, unauthorized, HttpError};
+use crate::transaction::api::{RelayTransactionRequest, SendTransactionResult};
+use crate::{
+    app_state::{AppState, NetworkValidateAction},
+    rate_limiting::RateLimitOperation,
+    relayer::RelayerId,
+    transaction::queue_system::TransactionToSend,
+};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SendBatchTransactionsRequest {
+    pub transactions: Vec<RelayTransactionRequest>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SendBatchTransactionsResult {
+    #[serde(rename = "batchId")]
+    pub batch_id: Uuid,
+    pub transactions: Vec<SendTransactionResult>,
+}
+
+/// API endpoint to send an ordered batch of transactions through a relayer as a single unit.
+///
+/// Every member of the batch shares the response's `batchId` while keeping its own `externalId`,
+/// and is assigned a contiguous nonce block so they land on-chain in submission order. The batch
+/// is validated as a whole - the same checks `send_transaction` runs for a single transaction -
+/// before anything is queued, but this is not an all-or-nothing operation: once validation
+/// passes, each member is admitted, priced, and persisted individually, so a downstream failure
+/// (e.g. gas estimation) partway through still leaves the earlier members queued. A caller that
+/// gets an error back must not blindly retry the whole batch - see `AddTransactionError::BatchPartiallyApplied`,
+/// whose already-applied transaction ids are included in the error response.
+pub async fn handle_send_batch_transactions(
+    State(state): State<Arc<AppState>>,
+    Path(relayer_id): Path<RelayerId>,
+    headers: HeaderMap,
+    Json(request): Json<SendBatchTransactionsRequest>,
+) -> Result<Json<SendBatchTransactionsResult>, HttpError> {
+    state.validate_allowed_passed_basic_auth(&headers)?;
+
+    let relayer = get_relayer(&state.db, &state.cache, &relayer_id)
+        .await?
+        .ok_or(not_found("Relayer does not exist".to_string()))?;
+
+    let result = send_batch_transactions(relayer, request, &state, &headers).await?;
+
+    Ok(Json(result))
+}
+
+pub async fn send_batch_transactions(
+    relayer: Relayer,
+    request: SendBatchTransactionsRequest,
+    state: &Arc<AppState>,
+    headers: &HeaderMap,
+) -> Result<SendBatchTransactionsResult, HttpError> {
+    if request.transactions.is_empty() {
+        return Err(bad_request("Batch must contain at least one transaction".to_string()));
+    }
+
+    state.validate_auth_basic_or_api_key(headers, &relayer.address, &relayer.chain_id)?;
+
+    if state.relayer_internal_only.restricted(&relayer.address, &relayer.chain_id) {
+        return Err(unauthorized(Some("Relayer can only be used internally".to_string())));
+    }
+
+    let network_config = state
+        .network_configs
+        .iter()
+        .find(|n| n.chain_id == relayer.chain_id)
+        .ok_or_else(|| internal_server_error(Some("Network configuration not found".to_string())))?;
+
+    let mut transactions_to_send = Vec::with_capacity(request.transactions.len());
+    for transaction in &request.transactions {
+        state.network_permission_validate(
+            &relayer.address,
+            &relayer.chain_id,
+            &transaction.to,
+            &transaction.value,
+            NetworkValidateAction::Transaction,
+        )?;
+
+        if transaction.blobs.is_some() && !network_config.enable_sending_blobs.unwrap_or(false) {
+            return Err(internal_server_error(Some(
+                "Blob transactions are not enabled for this network".to_string(),
+            )));
+        }
+
+        transactions_to_send.push(TransactionToSend::new(
+            transaction.to,
+            transaction.value,
+            transaction.data.clone(),
+            transaction.speed.clone(),
+            convert_blob_strings_to_blobs(transaction.blobs.clone())?,
+            transaction.external_id.clone(),
+        ));
+    }
+
+    let rate_limit_reservation = RateLimiter::check_and_reserve_rate_limit(
+        state,
+        headers,
+        &relayer.id,
+        RateLimitOperation::Transaction,
+    )
+    .await?;
+
+    let batch_id = Uuid::new_v4();
+
+    let transactions = state
+        .transactions_queues
+        .lock()
+        .await
+        .add_transactions_batch(&relayer.id, batch_id, &transactions_to_send)
+        .await?;
+
+    let transactions = transactions
+        .into_iter()
+        .map(|transaction| {
+            Ok(SendTransactionResult {
+                id: transaction.id,
+                hash: transaction.known_transaction_hash.ok_or(internal_server_error(Some(
+                    "should always have a known transaction hash".to_string(),
+                )))?,
+            })
+        })
+        .collect::<Result<Vec<_>, HttpError>>()?;
+
+    if let Some(reservation) = rate_limit_reservation {
+        reservation.commit();
+    }
+
+    Ok(SendBatchTransactionsResult { batch_id, transactions })
+}
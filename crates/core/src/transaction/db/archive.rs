@@ -0,0 +1,104 @@
+use super::builders::build_transaction_from_transaction_view;
+use crate::{
+    postgres::{PostgresClient, PostgresError},
+    relayer::RelayerId,
+    shared::common_types::{PagingContext, PagingResult},
+    transaction::types::Transaction,
+};
+
+/// Transaction statuses that are terminal - nothing in the queue system will ever touch these rows
+/// again, so the retention subsystem is free to move them out of the hot table.
+const ARCHIVABLE_STATUSES: &str = "'CONFIRMED', 'FAILED', 'EXPIRED', 'FEECAPPED', 'RELAYEDFAILED'";
+
+const ARCHIVED_TRANSACTION_COLUMNS: &str = "
+    id, relayer_id, \"to\", \"from\", nonce, data, value, chain_id, gas_price,
+    sent_max_priority_fee_per_gas, sent_max_fee_per_gas, gas_limit, block_hash,
+    block_number, hash, speed, status, blobs, expires_at, expired_at, queued_at,
+    mined_at, failed_at, failed_reason, sent_at, confirmed_at, external_id,
+    resubmission_count, max_fee_cap, max_resubmissions, gas_used,
+    effective_gas_price, reverted, relayed
+";
+
+impl PostgresClient {
+    /// Moves terminal transactions older than `older_than_days` from `relayer.transaction` into
+    /// `relayer.archived_transaction` in one atomic statement. Returns how many rows were archived.
+    pub async fn archive_transactions_older_than(
+        &self,
+        older_than_days: u32,
+    ) -> Result<u64, PostgresError> {
+        let query = format!(
+            "
+                WITH moved AS (
+                    DELETE FROM relayer.transaction
+                    WHERE status IN ({ARCHIVABLE_STATUSES})
+                    AND queued_at < NOW() - make_interval(days => $1::int)
+                    RETURNING {ARCHIVED_TRANSACTION_COLUMNS}
+                )
+                INSERT INTO relayer.archived_transaction ({ARCHIVED_TRANSACTION_COLUMNS})
+                SELECT {ARCHIVED_TRANSACTION_COLUMNS} FROM moved;
+            "
+        );
+
+        self.execute(&query, &[&(older_than_days as i32)]).await
+    }
+
+    /// Moves every terminal transaction beyond the most recent `keep_last_per_relayer` (per
+    /// relayer, newest first by `queued_at`) from `relayer.transaction` into
+    /// `relayer.archived_transaction`. Returns how many rows were archived.
+    pub async fn archive_transactions_beyond_keep_count(
+        &self,
+        keep_last_per_relayer: u32,
+    ) -> Result<u64, PostgresError> {
+        let query = format!(
+            "
+                WITH ranked AS (
+                    SELECT id, ROW_NUMBER() OVER (
+                        PARTITION BY relayer_id ORDER BY queued_at DESC
+                    ) AS rank
+                    FROM relayer.transaction
+                    WHERE status IN ({ARCHIVABLE_STATUSES})
+                ),
+                moved AS (
+                    DELETE FROM relayer.transaction t
+                    USING ranked
+                    WHERE t.id = ranked.id
+                    AND ranked.rank > $1::bigint
+                    RETURNING {ARCHIVED_TRANSACTION_COLUMNS}
+                )
+                INSERT INTO relayer.archived_transaction ({ARCHIVED_TRANSACTION_COLUMNS})
+                SELECT {ARCHIVED_TRANSACTION_COLUMNS} FROM moved;
+            "
+        );
+
+        self.execute(&query, &[&(keep_last_per_relayer as i64)]).await
+    }
+
+    /// Reads archived transaction history for a relayer on demand, newest first.
+    pub async fn get_archived_transactions_for_relayer(
+        &self,
+        id: &RelayerId,
+        paging_context: &PagingContext,
+    ) -> Result<PagingResult<Transaction>, PostgresError> {
+        let query = format!(
+            "
+                SELECT {ARCHIVED_TRANSACTION_COLUMNS}
+                FROM relayer.archived_transaction
+                WHERE relayer_id = $1
+                ORDER BY queued_at DESC
+                LIMIT $2
+                OFFSET $3;
+            "
+        );
+
+        let rows = self
+            .query(&query, &[&id, &(paging_context.limit as i64), &(paging_context.offset as i64)])
+            .await?;
+
+        let results: Vec<Transaction> =
+            rows.iter().map(build_transaction_from_transaction_view).collect();
+
+        let result_count = results.len();
+
+        Ok(PagingResult::new(results, paging_context.next(result_count), paging_context.previous()))
+    }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use super::builders::build_transaction_from_transaction_view;
+use crate::{
+    postgres::{PostgresClient, PostgresError},
+    relayer::RelayerId,
+    shared::common_types::{PagingContext, PagingResult},
+    transaction::{
+        queue_system::NodeId,
+        types::{Transaction, TransactionStatus},
+    },
+};
+
+impl PostgresClient {
+    /// Claims a page of a relayer's transactions in the given status for `node_id`, stamping
+    /// `locked_by`/`locked_at` on every row it takes. Called once the relayer itself has already
+    /// been claimed via `claim_relayers_for_node`, so this never races another node over the same
+    /// relayer - it exists to carry the same lease onto the individual rows a crashed node may
+    /// have left mid-flight, and to let the heartbeat/reaper operate at transaction granularity
+    /// too. `FOR UPDATE SKIP LOCKED` combined with the final `ORDER BY nonce` keeps the result in
+    /// nonce order without ever blocking on a row another process still holds.
+    pub async fn claim_transactions_by_status_for_relayer(
+        &self,
+        node_id: &NodeId,
+        relayer_id: &RelayerId,
+        status: &TransactionStatus,
+        paging_context: &PagingContext,
+    ) -> Result<PagingResult<Transaction>, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    WITH claimable AS (
+                        SELECT id
+                        FROM relayer.transaction
+                        WHERE relayer_id = $1
+                        AND status = $2
+                        ORDER BY nonce ASC
+                        LIMIT $4
+                        OFFSET $5
+                        FOR UPDATE SKIP LOCKED
+                    ),
+                    claimed AS (
+                        UPDATE relayer.transaction t
+                        SET locked_by = $3, locked_at = NOW()
+                        FROM claimable
+                        WHERE t.id = claimable.id
+                        RETURNING t.*
+                    )
+                    SELECT * FROM claimed ORDER BY nonce ASC;
+                ",
+                &[
+                    relayer_id,
+                    status,
+                    node_id,
+                    &(paging_context.limit as i64),
+                    &(paging_context.offset as i64),
+                ],
+            )
+            .await?;
+
+        let results: Vec<Transaction> =
+            rows.iter().map(build_transaction_from_transaction_view).collect();
+
+        let result_count = results.len();
+
+        Ok(PagingResult::new(results, paging_context.next(result_count), paging_context.previous()))
+    }
+
+    /// Refreshes the lease on every in-flight (pending or in-mempool) transaction `node_id`
+    /// currently owns.
+    pub async fn heartbeat_claimed_transactions(
+        &self,
+        node_id: &NodeId,
+    ) -> Result<u64, PostgresError> {
+        self.execute(
+            "
+                UPDATE relayer.transaction
+                SET locked_at = NOW()
+                WHERE locked_by = $1
+                AND (status = $2 OR status = $3);
+            ",
+            &[node_id, &TransactionStatus::PENDING, &TransactionStatus::INMEMPOOL],
+        )
+        .await
+    }
+
+    /// Releases the lease on any in-flight transaction whose owning node has gone quiet for
+    /// longer than `lease`, mirroring `reclaim_expired_relayer_leases` at transaction granularity.
+    pub async fn reclaim_expired_transaction_leases(
+        &self,
+        lease: Duration,
+    ) -> Result<u64, PostgresError> {
+        self.execute(
+            "
+                UPDATE relayer.transaction
+                SET locked_by = NULL, locked_at = NULL
+                WHERE locked_by IS NOT NULL
+                AND locked_at < NOW() - make_interval(secs => $1)
+                AND (status = $2 OR status = $3);
+            ",
+            &[&(lease.as_secs() as f64), &TransactionStatus::PENDING, &TransactionStatus::INMEMPOOL],
+        )
+        .await
+    }
+}
@@ -0,0 +1,153 @@
+use tokio_postgres::Row;
+
+use crate::{
+    postgres::{PostgresClient, PostgresError},
+    relayer::RelayerId,
+    shared::common_types::{PagingContext, PagingResult},
+    transaction::types::{ScheduledTransaction, ScheduledTransactionId},
+};
+
+fn build_scheduled_transaction(row: &Row) -> ScheduledTransaction {
+    ScheduledTransaction {
+        id: row.get("id"),
+        relayer_id: row.get("relayer_id"),
+        to: row.get("to"),
+        value: row.get("value"),
+        data: row.get("data"),
+        speed: row.get("speed"),
+        external_id: row.get("external_id"),
+        period_in_seconds: row.get("period_in_seconds"),
+        next_run_at: row.get("next_run_at"),
+        cancelled: row.get("cancelled"),
+        created_at: row.get("created_at"),
+    }
+}
+
+impl PostgresClient {
+    /// Persists a newly registered scheduled (or recurring) transaction job.
+    pub async fn create_scheduled_transaction(
+        &self,
+        scheduled_transaction: &ScheduledTransaction,
+    ) -> Result<(), PostgresError> {
+        self.execute(
+            "
+                INSERT INTO relayer.scheduled_transaction
+                    (id, relayer_id, \"to\", value, data, speed, external_id, period_in_seconds, next_run_at)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9);
+            ",
+            &[
+                &scheduled_transaction.id,
+                &scheduled_transaction.relayer_id,
+                &scheduled_transaction.to,
+                &scheduled_transaction.value,
+                &scheduled_transaction.data,
+                &scheduled_transaction.speed,
+                &scheduled_transaction.external_id,
+                &scheduled_transaction.period_in_seconds,
+                &scheduled_transaction.next_run_at,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lists the scheduled transaction jobs registered for a relayer, most recently created first.
+    pub async fn get_scheduled_transactions_for_relayer(
+        &self,
+        relayer_id: &RelayerId,
+        paging_context: &PagingContext,
+    ) -> Result<PagingResult<ScheduledTransaction>, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    SELECT *
+                    FROM relayer.scheduled_transaction
+                    WHERE relayer_id = $1
+                    ORDER BY created_at DESC
+                    LIMIT $2
+                    OFFSET $3;
+                ",
+                &[relayer_id, &(paging_context.limit as i64), &(paging_context.offset as i64)],
+            )
+            .await?;
+
+        let results: Vec<ScheduledTransaction> = rows.iter().map(build_scheduled_transaction).collect();
+
+        let result_count = results.len();
+
+        Ok(PagingResult::new(results, paging_context.next(result_count), paging_context.previous()))
+    }
+
+    /// Cancels a scheduled transaction job so it no longer fires. Returns `false` if the job
+    /// doesn't exist or was already cancelled.
+    pub async fn cancel_scheduled_transaction(
+        &self,
+        id: &ScheduledTransactionId,
+    ) -> Result<bool, PostgresError> {
+        let rows_affected = self
+            .execute(
+                "
+                    UPDATE relayer.scheduled_transaction
+                    SET cancelled = TRUE
+                    WHERE id = $1
+                    AND cancelled = FALSE;
+                ",
+                &[id],
+            )
+            .await?;
+
+        Ok(rows_affected > 0)
+    }
+
+    /// Claims every scheduled transaction job for `relayer_id` that is due to fire, advancing
+    /// recurring jobs to their next run time and marking one-off jobs cancelled, all in the same
+    /// statement that reads them out. `FOR UPDATE SKIP LOCKED` means a slow-running ticker tick
+    /// never double-fires a job another tick already grabbed.
+    ///
+    /// A recurring job advances by however many whole periods it takes to land back in the
+    /// future, not by a single fixed period - otherwise any downtime longer than one period
+    /// (a deploy, a crash, backpressure) would leave `next_run_at` in the past, and the ticker
+    /// would re-claim and re-fire the same job on every tick until it finally caught up.
+    pub async fn claim_due_scheduled_transactions_for_relayer(
+        &self,
+        relayer_id: &RelayerId,
+    ) -> Result<Vec<ScheduledTransaction>, PostgresError> {
+        let rows = self
+            .query(
+                "
+                    WITH due AS (
+                        SELECT id
+                        FROM relayer.scheduled_transaction
+                        WHERE relayer_id = $1
+                        AND cancelled = FALSE
+                        AND next_run_at <= NOW()
+                        ORDER BY next_run_at ASC
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    UPDATE relayer.scheduled_transaction s
+                    SET
+                        next_run_at = CASE
+                            WHEN s.period_in_seconds IS NOT NULL
+                            THEN s.next_run_at + make_interval(secs => s.period_in_seconds * GREATEST(
+                                CEIL(GREATEST(EXTRACT(EPOCH FROM (NOW() - s.next_run_at)), 0) / s.period_in_seconds),
+                                1
+                            ))
+                            ELSE s.next_run_at
+                        END,
+                        cancelled = CASE
+                            WHEN s.period_in_seconds IS NULL THEN TRUE
+                            ELSE s.cancelled
+                        END
+                    FROM due
+                    WHERE s.id = due.id
+                    RETURNING s.*;
+                ",
+                &[relayer_id],
+            )
+            .await?;
+
+        Ok(rows.iter().map(build_scheduled_transaction).collect())
+    }
+}
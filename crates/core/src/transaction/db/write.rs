@@ -7,6 +7,7 @@ use crate::{
         common_types::{BlockHash, BlockNumber},
         utils::option_if,
     },
+    transaction::queue_system::notify_channel_name,
     transaction::types::{
         Transaction, TransactionData, TransactionHash, TransactionId, TransactionNonce,
         TransactionStatus, TransactionValue,
@@ -54,6 +55,13 @@ impl PostgresClient {
                 .await?;
         }
 
+        trans
+            .execute(
+                "SELECT pg_notify($1, $2)",
+                &[&notify_channel_name(relayer_id), &"PENDING"],
+            )
+            .await?;
+
         trans.commit().await?;
 
         Ok(())
@@ -61,6 +69,7 @@ impl PostgresClient {
 
     pub async fn transaction_sent(
         &mut self,
+        relayer_id: &RelayerId,
         transaction_id: &TransactionId,
         transaction_hash: &TransactionHash,
         sent_with_gas: &GasPriceResult,
@@ -137,6 +146,13 @@ impl PostgresClient {
             )
             .await?;
 
+        trans
+            .execute(
+                "SELECT pg_notify($1, $2)",
+                &[&notify_channel_name(relayer_id), &"INMEMPOOL"],
+            )
+            .await?;
+
         trans.commit().await?;
 
         Ok(())
@@ -286,6 +302,70 @@ impl PostgresClient {
         Ok(())
     }
 
+    /// Marks a relayed (forced-inclusion) transaction as failed - rejected, reverted, or dropped -
+    /// recording the block it was decided in and why.
+    pub async fn mark_transaction_relayed_failed(
+        &mut self,
+        transaction_id: &TransactionId,
+        block_number: &BlockNumber,
+        reason: &str,
+    ) -> Result<(), PostgresError> {
+        let mut conn = self.pool.get().await?;
+        let trans = conn.transaction().await.map_err(PostgresError::PgError)?;
+
+        let truncated_reason = reason.chars().take(2000).collect::<String>();
+
+        trans
+            .execute(
+                "
+                    UPDATE relayer.transaction
+                    SET status = $2,
+                        failed_at = NOW(),
+                        failed_reason = $3,
+                        block_number = $4,
+                        relayed = TRUE
+                    WHERE id = $1;
+                ",
+                &[
+                    &transaction_id,
+                    &TransactionStatus::RELAYEDFAILED,
+                    &truncated_reason,
+                    block_number,
+                ],
+            )
+            .await?;
+
+        trans
+            .execute(
+                "
+                    INSERT INTO relayer.transaction_audit_log (
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, status, expires_at, queued_at, sent_at, mined_at, confirmed_at,
+                        failed_at, failed_reason, block_number, relayed, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, external_id
+                    )
+                    SELECT
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, $2, expires_at, queued_at, sent_at, mined_at, confirmed_at,
+                        NOW(), $3, $4, TRUE, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, external_id
+                    FROM relayer.transaction
+                    WHERE id = $1;
+                ",
+                &[
+                    &transaction_id,
+                    &TransactionStatus::RELAYEDFAILED,
+                    &truncated_reason,
+                    block_number,
+                ],
+            )
+            .await?;
+
+        trans.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn transaction_mined(
         &mut self,
         transaction: &Transaction,
@@ -295,6 +375,8 @@ impl PostgresClient {
         let trans = conn.transaction().await.map_err(PostgresError::PgError)?;
 
         let gas_used = GasLimit::from(transaction_receipt.gas_used);
+        let effective_gas_price = transaction.effective_gas_price_from_receipt(transaction_receipt);
+        let reverted = !transaction_receipt.status();
         let block_hash = transaction_receipt.block_hash.map(BlockHash::new);
         let block_number = transaction_receipt.block_number.map(BlockNumber::new);
 
@@ -317,6 +399,9 @@ impl PostgresClient {
                     sent_max_fee_per_gas = $14,
                     sent_max_priority_fee_per_gas = $15,
                     external_id = $16,
+                    gas_used = $17,
+                    effective_gas_price = $18,
+                    reverted = $19,
                     mined_at = NOW()
                 WHERE id = $1;
             ",
@@ -329,7 +414,7 @@ impl PostgresClient {
                     &transaction.data,
                     &transaction.nonce,
                     &transaction.chain_id,
-                    &gas_used,
+                    &transaction.gas_limit,
                     &block_hash,
                     &block_number,
                     &transaction.speed,
@@ -337,6 +422,9 @@ impl PostgresClient {
                     &transaction.sent_with_max_fee_per_gas,
                     &transaction.sent_with_max_priority_fee_per_gas,
                     &transaction.external_id,
+                    &gas_used,
+                    &effective_gas_price,
+                    &reverted,
                 ],
             )
             .await?;
@@ -348,12 +436,14 @@ impl PostgresClient {
                     id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
                     speed, status, expires_at, queued_at, sent_at, mined_at, confirmed_at,
                     failed_at, failed_reason, hash, sent_max_priority_fee_per_gas,
-                    sent_max_fee_per_gas, gas_price, block_hash, block_number, external_id
+                    sent_max_fee_per_gas, gas_price, block_hash, block_number, external_id,
+                    gas_used, effective_gas_price, reverted
                 )
-                SELECT 
+                SELECT
                     $1, relayer_id, $3, $4, $7, $8, $6, $5, blobs, $9,
                     $12, $2, expires_at, queued_at, sent_at, NOW(), confirmed_at,
-                    failed_at, failed_reason, $13, $15, $14, gas_price, $10, $11, $16
+                    failed_at, failed_reason, $13, $15, $14, gas_price, $10, $11, $16,
+                    $17, $18, $19
                 FROM relayer.transaction
                 WHERE id = $1;
             ",
@@ -366,7 +456,7 @@ impl PostgresClient {
                     &transaction.data,
                     &transaction.nonce,
                     &transaction.chain_id,
-                    &gas_used,
+                    &transaction.gas_limit,
                     &block_hash,
                     &block_number,
                     &transaction.speed,
@@ -374,10 +464,20 @@ impl PostgresClient {
                     &transaction.sent_with_max_fee_per_gas,
                     &transaction.sent_with_max_priority_fee_per_gas,
                     &transaction.external_id,
+                    &gas_used,
+                    &effective_gas_price,
+                    &reverted,
                 ],
             )
             .await?;
 
+        trans
+            .execute(
+                "SELECT pg_notify($1, $2)",
+                &[&notify_channel_name(&transaction.relayer_id), &"MINED"],
+            )
+            .await?;
+
         trans.commit().await?;
         Ok(())
     }
@@ -513,6 +613,112 @@ impl PostgresClient {
         Ok(())
     }
 
+    /// Marks a transaction as `FEECAPPED`: the fee escalator hit its resubmission ceiling (max
+    /// attempts or max fee cap) before the transaction got mined, so it is no longer being
+    /// bumped or rebroadcast automatically.
+    pub async fn update_transaction_fee_capped(
+        &mut self,
+        transaction_id: &TransactionId,
+    ) -> Result<(), PostgresError> {
+        let mut conn = self.pool.get().await?;
+        let trans = conn.transaction().await.map_err(PostgresError::PgError)?;
+
+        trans
+            .execute(
+                "
+                    UPDATE relayer.transaction
+                    SET status = $2
+                    WHERE id = $1;
+                ",
+                &[&transaction_id, &TransactionStatus::FEECAPPED],
+            )
+            .await?;
+
+        trans
+            .execute(
+                "
+                    INSERT INTO relayer.transaction_audit_log (
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, status, expires_at, queued_at, sent_at, mined_at, confirmed_at,
+                        failed_at, failed_reason, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, external_id
+                    )
+                    SELECT
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, $2, expires_at, queued_at, sent_at, mined_at, confirmed_at,
+                        failed_at, failed_reason, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, external_id
+                    FROM relayer.transaction
+                    WHERE id = $1;
+                ",
+                &[&transaction_id, &TransactionStatus::FEECAPPED],
+            )
+            .await?;
+
+        trans.commit().await?;
+
+        Ok(())
+    }
+
+    /// Rolls a mined transaction back to `INMEMPOOL` because the block it was mined in fell off
+    /// the canonical chain in a reorg. Clears `mined_at`/`block_hash`/`block_number` so the
+    /// transaction is processed exactly as if it had never been mined, and resumes tracking from
+    /// there - the relayer's mempool loop will pick it back up and wait for it to land (in the
+    /// same or a different block) again.
+    pub async fn transaction_reorged(
+        &mut self,
+        transaction: &Transaction,
+    ) -> Result<(), PostgresError> {
+        let mut conn = self.pool.get().await?;
+        let trans = conn.transaction().await.map_err(PostgresError::PgError)?;
+
+        trans
+            .execute(
+                "
+                    UPDATE relayer.transaction
+                    SET status = $2,
+                        block_hash = NULL,
+                        block_number = NULL,
+                        mined_at = NULL
+                    WHERE id = $1;
+                ",
+                &[&transaction.id, &TransactionStatus::INMEMPOOL],
+            )
+            .await?;
+
+        trans
+            .execute(
+                "
+                    INSERT INTO relayer.transaction_audit_log (
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, status, expires_at, queued_at, sent_at, mined_at, confirmed_at,
+                        failed_at, failed_reason, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, block_hash, block_number, external_id
+                    )
+                    SELECT
+                        id, relayer_id, \"to\", \"from\", nonce, chain_id, data, value, blobs, gas_limit,
+                        speed, $2, expires_at, queued_at, sent_at, NULL, confirmed_at,
+                        failed_at, failed_reason, hash, sent_max_priority_fee_per_gas,
+                        sent_max_fee_per_gas, gas_price, NULL, NULL, external_id
+                    FROM relayer.transaction
+                    WHERE id = $1;
+                ",
+                &[&transaction.id, &TransactionStatus::INMEMPOOL],
+            )
+            .await?;
+
+        trans
+            .execute(
+                "SELECT pg_notify($1, $2)",
+                &[&notify_channel_name(&transaction.relayer_id), &"INMEMPOOL"],
+            )
+            .await?;
+
+        trans.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn transaction_update(&self, transaction: &Transaction) -> Result<(), PostgresError> {
         let mut conn = self.pool.get().await?;
         let trans = conn.transaction().await.map_err(PostgresError::PgError)?;
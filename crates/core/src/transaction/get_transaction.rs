@@ -39,3 +39,23 @@ pub async fn get_transaction_by_id(
 
     Ok(transaction)
 }
+
+/// Retrieves a relayed (forced-inclusion) transaction by its ID, analogous to `get_relayer`.
+///
+/// Returns `Ok(None)` both when no transaction exists with this ID and when a transaction exists
+/// but isn't marked `relayed`, so callers can't use this to probe for the existence of a normal,
+/// non-relayed transaction.
+///
+/// # Arguments
+/// * `cache` - The cache instance to check for cached transactions
+/// * `db` - The PostgreSQL database client to query if cache miss occurs
+/// * `id` - The relayed transaction's ID
+pub async fn get_relayed_transaction_by_id(
+    cache: &Arc<Cache>,
+    db: &PostgresClient,
+    id: TransactionId,
+) -> Result<Option<Transaction>, PostgresError> {
+    let transaction = get_transaction_by_id(cache, db, id).await?;
+
+    Ok(transaction.filter(|transaction| transaction.relayed))
+}
@@ -0,0 +1,76 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use super::types::NodeId;
+use crate::postgres::PostgresClient;
+
+/// How many relayers a single claim pass grabs at once. Large enough that a handful of nodes
+/// converge on a balanced split within a couple of rebalance passes, small enough that one node
+/// claiming a sudden backlog of unowned relayers can't starve every other node out of the round.
+pub const CLAIM_BATCH_SIZE: i64 = 200;
+
+/// Default lease a node holds on a relayer (and that relayer's in-flight transactions) before
+/// another node is allowed to treat it as abandoned and reclaim it. Comfortably longer than
+/// `HEARTBEAT_INTERVAL` so a handful of missed heartbeats under load don't trigger a false
+/// reclaim while the owning node is still very much alive.
+pub const DEFAULT_LEASE: Duration = Duration::from_secs(30);
+
+/// How often a node refreshes the lease on everything it currently owns.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often the reaper checks for leases abandoned by a crashed node.
+const REAP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Spawns the heartbeat task that keeps `node_id`'s claimed relayers, and their in-flight
+/// transactions, from being treated as abandoned while the node is still alive. Runs forever.
+pub fn spawn_lease_heartbeat(db: Arc<PostgresClient>, node_id: NodeId) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = db.heartbeat_claimed_relayers(&node_id).await {
+                error!("Node {} failed to refresh its relayer lease heartbeat: {}", node_id, e);
+            }
+
+            if let Err(e) = db.heartbeat_claimed_transactions(&node_id).await {
+                error!("Node {} failed to refresh its transaction lease heartbeat: {}", node_id, e);
+            }
+        }
+    });
+}
+
+/// Spawns the reaper task that releases leases abandoned by a crashed node, so the next rebalance
+/// pass - on this node or another - can claim the work instead of it sitting idle forever. Runs
+/// forever.
+pub fn spawn_lease_reaper(db: Arc<PostgresClient>, lease: Duration) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REAP_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            match db.reclaim_expired_relayer_leases(lease).await {
+                Ok(0) => {}
+                Ok(count) => {
+                    info!(
+                        "Reaper released {} relayer lease(s) abandoned by a crashed node",
+                        count
+                    );
+                }
+                Err(e) => error!("Relayer lease reaper failed: {}", e),
+            }
+
+            match db.reclaim_expired_transaction_leases(lease).await {
+                Ok(0) => {}
+                Ok(count) => {
+                    info!(
+                        "Reaper released {} transaction lease(s) abandoned by a crashed node",
+                        count
+                    );
+                }
+                Err(e) => error!("Transaction lease reaper failed: {}", e),
+            }
+        }
+    });
+}
@@ -3,7 +3,25 @@ mod transactions_queues;
 pub use transactions_queues::TransactionsQueues;
 
 mod types;
-pub use types::{TransactionToSend, TransactionsQueueSetup};
+pub use types::{NodeId, NonceCap, TransactionToSend, TransactionsQueueSetup};
+
+mod notify;
+pub use notify::{notify_channel_name, spawn_relayer_notification_listener, RelayerQueueNotify};
+
+mod claim;
+pub use claim::{spawn_lease_heartbeat, spawn_lease_reaper, CLAIM_BATCH_SIZE, DEFAULT_LEASE};
+
+mod scheduler;
+pub use scheduler::spawn_scheduled_transaction_ticker;
+
+mod stuck_transaction_rescue;
+pub use stuck_transaction_rescue::spawn_stuck_transaction_rescue_ticker;
+
+mod retention;
+pub use retention::spawn_transaction_retention_task;
+
+mod tasks;
+pub use tasks::RelayerTaskRegistry;
 
 mod start;
 pub use start::{startup_transactions_queues, StartTransactionsQueuesError};
@@ -0,0 +1,203 @@
+use std::{sync::Arc, time::Duration};
+
+use native_tls::TlsConnector;
+use postgres_native_tls::MakeTlsConnector;
+use tokio::{
+    sync::{mpsc, Mutex, Notify},
+    time::timeout,
+};
+use tokio_postgres::{config::SslMode, AsyncMessage, Config};
+use tracing::{error, info, warn};
+
+use crate::{postgres::connection_string, relayer::RelayerId};
+
+use super::transactions_queues::TransactionsQueues;
+
+/// How often the listener re-checks the set of known relayers and issues `LISTEN` for any it
+/// hasn't subscribed to yet (e.g. a relayer created after the listener connected).
+const SUBSCRIPTION_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long to wait before reconnecting after the listener connection drops.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// How long to wait for the initial connection before giving up and retrying.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(5000);
+
+/// A relayer's three `Notify` handles, one per processing queue. Cloning is cheap - it only
+/// clones the underlying `Arc`s - so the same handles can be held by both the processing loop in
+/// `start.rs` (to wait on) and the notification listener below (to fire).
+#[derive(Clone)]
+pub struct RelayerQueueNotify {
+    pub pending: Arc<Notify>,
+    pub inmempool: Arc<Notify>,
+    pub mined: Arc<Notify>,
+}
+
+impl RelayerQueueNotify {
+    pub fn new() -> Self {
+        Self {
+            pending: Arc::new(Notify::new()),
+            inmempool: Arc::new(Notify::new()),
+            mined: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Fires every queue once. Used after the listener reconnects, since a NOTIFY sent while we
+    /// were disconnected is lost forever and the only way to recover is to force each loop to
+    /// reconcile against the database.
+    fn notify_all(&self) {
+        self.pending.notify_one();
+        self.inmempool.notify_one();
+        self.mined.notify_one();
+    }
+}
+
+impl Default for RelayerQueueNotify {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds the Postgres `LISTEN`/`NOTIFY` channel name for a relayer. Hyphens aren't valid in an
+/// unquoted identifier, so they're swapped for underscores instead of quoting the channel name.
+pub fn notify_channel_name(relayer_id: &RelayerId) -> String {
+    format!("rrelayer_tx_{}", relayer_id.to_string().replace('-', "_"))
+}
+
+/// Recovers the relayer a notification came in on from the channel name it was sent on.
+fn relayer_id_from_channel(channel: &str) -> Option<RelayerId> {
+    let suffix = channel.strip_prefix("rrelayer_tx_")?;
+    suffix.replacen('_', "-", 4).parse().ok()
+}
+
+/// Spawns the long-lived task that relays Postgres `NOTIFY rrelayer_tx_<relayer_id>` messages
+/// into the matching relayer's `Notify` handles, so the processing loops in `start.rs` wake up
+/// immediately instead of waiting for their next `sleep_ms` fallback. Reconnects on failure and
+/// never returns.
+pub async fn spawn_relayer_notification_listener(
+    transactions_queues: Arc<Mutex<TransactionsQueues>>,
+) {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = run_listener(&transactions_queues).await {
+                error!(
+                    "Relayer notification listener disconnected, reconnecting: {}",
+                    e
+                );
+            }
+
+            // A NOTIFY sent while we were disconnected is gone for good - force every relayer's
+            // queues to take a reconciliation pass once we're back, per the fallback-timer
+            // invariant this subsystem relies on.
+            let handles = transactions_queues.lock().await.notify_handles().await;
+            for notify in handles.values() {
+                notify.notify_all();
+            }
+
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    });
+}
+
+/// Connects a dedicated (non-pooled) Postgres connection for LISTEN, retrying once without TLS
+/// the same way `PostgresClient::new()` does, since LISTEN state is connection-scoped and can't
+/// be served out of the pool.
+async fn connect() -> Result<
+    (
+        tokio_postgres::Client,
+        tokio_postgres::Connection<
+            tokio_postgres::Socket,
+            postgres_native_tls::TlsStream<tokio_postgres::Socket>,
+        >,
+    ),
+    String,
+> {
+    let connection_str = connection_string().map_err(|e| e.to_string())?;
+    let mut config: Config = connection_str
+        .parse()
+        .map_err(|_| "could not parse connection string".to_string())?;
+
+    let connector = TlsConnector::builder()
+        .build()
+        .map_err(|_| "could not create tls connector".to_string())?;
+    let tls_connector = MakeTlsConnector::new(connector);
+
+    match timeout(CONNECT_TIMEOUT, config.connect(tls_connector.clone())).await {
+        Ok(Ok(pair)) => Ok(pair),
+        Ok(Err(e)) => {
+            if config.get_ssl_mode() != SslMode::Disable
+                && !connection_str.contains("sslmode=require")
+            {
+                config.ssl_mode(SslMode::Disable);
+                config
+                    .connect(tls_connector)
+                    .await
+                    .map_err(|e| e.to_string())
+            } else {
+                Err(e.to_string())
+            }
+        }
+        Err(e) => Err(format!("timed out connecting: {}", e)),
+    }
+}
+
+async fn run_listener(transactions_queues: &Arc<Mutex<TransactionsQueues>>) -> Result<(), String> {
+    let (client, connection) = connect().await?;
+
+    // The `Connection` has to be polled continuously for any request (including our `LISTEN`
+    // calls below) to be written and its response read back, so it's driven on its own task and
+    // its notifications relayed into a channel rather than polled inline in the select loop.
+    let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut connection = connection;
+        loop {
+            match std::future::poll_fn(|cx| connection.poll_message(cx)).await {
+                Some(Ok(message)) => {
+                    if notification_tx.send(message).is_err() {
+                        break;
+                    }
+                }
+                Some(Err(_)) | None => break,
+            }
+        }
+    });
+
+    let mut subscribed: std::collections::HashSet<RelayerId> = Default::default();
+    let mut refresh = tokio::time::interval(SUBSCRIPTION_REFRESH_INTERVAL);
+
+    loop {
+        tokio::select! {
+            message = notification_rx.recv() => {
+                match message {
+                    Some(AsyncMessage::Notification(notification)) => {
+                        if let Some(relayer_id) = relayer_id_from_channel(notification.channel()) {
+                            let handles = transactions_queues.lock().await.notify_handles().await;
+                            if let Some(notify) = handles.get(&relayer_id) {
+                                match notification.payload() {
+                                    "PENDING" => notify.pending.notify_one(),
+                                    "INMEMPOOL" => notify.inmempool.notify_one(),
+                                    "MINED" => notify.mined.notify_one(),
+                                    other => warn!("Unknown transaction notification payload: {}", other),
+                                }
+                            }
+                        }
+                    }
+                    Some(_) => {}
+                    None => return Err("connection closed".to_string()),
+                }
+            }
+            _ = refresh.tick() => {
+                let handles = transactions_queues.lock().await.notify_handles().await;
+                for relayer_id in handles.keys() {
+                    if subscribed.insert(*relayer_id) {
+                        client
+                            .batch_execute(&format!("LISTEN {}", notify_channel_name(relayer_id)))
+                            .await
+                            .map_err(|e| e.to_string())?;
+                    }
+                }
+                info!("Listening for transaction notifications on {} relayer channel(s)", subscribed.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::{sync::Arc, time::Duration};
+
+use tracing::{error, info};
+
+use crate::{postgres::PostgresClient, yaml::TransactionRetentionConfig};
+
+/// How often the retention task sweeps for transactions eligible to be archived. Infrequent
+/// enough that the archival pass is never the bottleneck; frequent enough that the hot table
+/// doesn't grow unbounded between sweeps on a busy relayer.
+const RETENTION_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Spawns the retention task that periodically moves terminal transactions out of the hot
+/// `relayer.transaction` table into `relayer.archived_transaction`, per `config`. Runs forever.
+/// Does nothing if neither retention knob is configured.
+pub fn spawn_transaction_retention_task(db: Arc<PostgresClient>, config: TransactionRetentionConfig) {
+    if config.archive_after_days.is_none() && config.keep_last_per_relayer.is_none() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RETENTION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            if let Some(older_than_days) = config.archive_after_days {
+                match db.archive_transactions_older_than(older_than_days).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        info!("Retention task archived {} transaction(s) older than {} day(s)", count, older_than_days);
+                    }
+                    Err(e) => {
+                        error!("Retention task failed to archive transactions by age: {}", e);
+                    }
+                }
+            }
+
+            if let Some(keep_last_per_relayer) = config.keep_last_per_relayer {
+                match db.archive_transactions_beyond_keep_count(keep_last_per_relayer).await {
+                    Ok(0) => {}
+                    Ok(count) => {
+                        info!(
+                            "Retention task archived {} transaction(s) beyond the most recent {} per relayer",
+                            count, keep_last_per_relayer
+                        );
+                    }
+                    Err(e) => {
+                        error!("Retention task failed to archive transactions by keep-count: {}", e);
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,67 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::Mutex;
+use tracing::error;
+
+use super::{transactions_queues::TransactionsQueues, types::TransactionToSend};
+use crate::{postgres::PostgresClient, relayer::RelayerId};
+
+/// How often a relayer's ticker checks for scheduled transactions that have come due.
+const SCHEDULE_TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Spawns the ticker that fires a relayer's due scheduled (and recurring) transactions into its
+/// pending queue, the same way a direct call to `add_transaction` would. Runs forever, stopping
+/// only when the relayer's queue is gone (deleted, or owned by another node after a rebalance).
+pub fn spawn_scheduled_transaction_ticker(
+    db: Arc<PostgresClient>,
+    transactions_queues: Arc<Mutex<TransactionsQueues>>,
+    relayer_id: RelayerId,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULE_TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let still_running =
+                transactions_queues.lock().await.get_transactions_queue(&relayer_id).is_some();
+            if !still_running {
+                break;
+            }
+
+            let due = match db.claim_due_scheduled_transactions_for_relayer(&relayer_id).await {
+                Ok(due) => due,
+                Err(e) => {
+                    error!(
+                        "Failed to claim due scheduled transactions for relayer {}: {}",
+                        relayer_id, e
+                    );
+                    continue;
+                }
+            };
+
+            for job in due {
+                let transaction_to_send = TransactionToSend::new(
+                    job.to,
+                    job.value,
+                    job.data,
+                    Some(job.speed),
+                    None,
+                    job.external_id,
+                );
+
+                let add_result = transactions_queues
+                    .lock()
+                    .await
+                    .add_transaction(&relayer_id, &transaction_to_send)
+                    .await;
+
+                if let Err(e) = add_result {
+                    error!(
+                        "Failed to enqueue due scheduled transaction {} for relayer {}: {}",
+                        job.id, relayer_id, e
+                    );
+                }
+            }
+        }
+    });
+}
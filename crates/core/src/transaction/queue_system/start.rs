@@ -1,12 +1,24 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
+use rand::{thread_rng, Rng};
 use thiserror::Error;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
-use super::{transactions_queues::TransactionsQueues, types::TransactionRelayerSetup};
+use super::{
+    claim::{spawn_lease_heartbeat, spawn_lease_reaper, CLAIM_BATCH_SIZE, DEFAULT_LEASE},
+    notify::spawn_relayer_notification_listener,
+    retention::spawn_transaction_retention_task,
+    scheduler::spawn_scheduled_transaction_ticker,
+    stuck_transaction_rescue::spawn_stuck_transaction_rescue_ticker,
+    tasks::RelayerTaskRegistry,
+    transactions_queues::TransactionsQueues,
+    types::{NodeId, NonceCap, TransactionRelayerSetup},
+};
 use crate::transaction::queue_system::types::{
     ProcessInmempoolTransactionError, ProcessMinedTransactionError, ProcessPendingTransactionError,
+    TransactionQueueSendTransactionError,
 };
 use crate::webhooks::WebhookManager;
 use crate::{
@@ -22,40 +34,77 @@ use crate::{
         utils::sleep_ms,
     },
     transaction::types::{Transaction, TransactionStatus},
+    yaml::TransactionRetentionConfig,
 };
 
+/// How often the rebalance task re-runs the claim query, picking up relayers that are brand new
+/// or that the reaper just freed from a crashed node. Adding a node to the fleet doesn't require
+/// any manual rebalancing - idle relayers simply get claimed by whichever node's rebalance pass
+/// sees them first.
+const REBALANCE_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Spawns processing tasks for a single relayer.
+///
+/// Each of the three loops gets its own clone of a single `CancellationToken` for this relayer,
+/// and their join handles are registered with `registry` so the relayer's tasks can be torn down
+/// on demand - either individually (when the relayer is removed) or all together (on process
+/// shutdown) - without waiting for each loop to notice its queue is gone on its own.
 pub async fn spawn_processing_tasks_for_relayer(
     transaction_queue: Arc<Mutex<TransactionsQueues>>,
     relayer_id: &RelayerId,
+    registry: &RelayerTaskRegistry,
 ) {
+    let cancellation_token = CancellationToken::new();
+
     let queue_clone_pending = transaction_queue.clone();
     let relayer_id_pending = *relayer_id;
-    tokio::spawn(async move {
-        continuously_process_pending_transactions(queue_clone_pending, &relayer_id_pending).await;
+    let cancellation_token_pending = cancellation_token.clone();
+    let pending = tokio::spawn(async move {
+        continuously_process_pending_transactions(
+            queue_clone_pending,
+            &relayer_id_pending,
+            cancellation_token_pending,
+        )
+        .await;
     });
 
     let queue_clone_inmempool = transaction_queue.clone();
     let relayer_id_inmempool = *relayer_id;
-    tokio::spawn(async move {
-        continuously_process_inmempool_transactions(queue_clone_inmempool, &relayer_id_inmempool)
-            .await;
+    let cancellation_token_inmempool = cancellation_token.clone();
+    let inmempool = tokio::spawn(async move {
+        continuously_process_inmempool_transactions(
+            queue_clone_inmempool,
+            &relayer_id_inmempool,
+            cancellation_token_inmempool,
+        )
+        .await;
     });
 
     let queue_clone_mined = transaction_queue.clone();
     let relayer_id_mined = *relayer_id;
-    tokio::spawn(async move {
-        continuously_process_mined_transactions(queue_clone_mined, &relayer_id_mined).await;
+    let cancellation_token_mined = cancellation_token.clone();
+    let mined = tokio::spawn(async move {
+        continuously_process_mined_transactions(
+            queue_clone_mined,
+            &relayer_id_mined,
+            cancellation_token_mined,
+        )
+        .await;
     });
+
+    registry.register(*relayer_id, cancellation_token, pending, inmempool, mined).await;
 }
 
 /// Spawns background processing tasks for all transaction queues.
 async fn spawn_processing_tasks(transaction_queue: Arc<Mutex<TransactionsQueues>>) {
-    let relay_ids: Vec<RelayerId> =
-        { transaction_queue.lock().await.queues.keys().cloned().collect() };
+    let (relay_ids, registry): (Vec<RelayerId>, RelayerTaskRegistry) = {
+        let queues = transaction_queue.lock().await;
+        (queues.queues.keys().cloned().collect(), queues.relayer_task_registry())
+    };
 
     for relayer_id in relay_ids {
-        spawn_processing_tasks_for_relayer(transaction_queue.clone(), &relayer_id).await;
+        spawn_processing_tasks_for_relayer(transaction_queue.clone(), &relayer_id, &registry)
+            .await;
     }
 }
 
@@ -64,6 +113,65 @@ async fn processes_next_break(process_again_after_ms: &u64) {
     sleep_ms(process_again_after_ms).await
 }
 
+/// Base delay for the first retry after a processing loop error.
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+/// Ceiling the exponential backoff delay is clamped to.
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// Consecutive failures after which the loop stops retrying on a normal backoff and opens the
+/// circuit instead.
+const CIRCUIT_OPEN_THRESHOLD: u32 = 5;
+/// How long the loop cools down for once the circuit is open, before trying again.
+const CIRCUIT_OPEN_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Tracks consecutive failures for a single processing loop (pending/inmempool/mined, per
+/// relayer) so a failing RPC provider or a transient Postgres error backs off instead of
+/// hot-spinning. Once `CIRCUIT_OPEN_THRESHOLD` consecutive failures have happened, the loop stops
+/// logging every single error - which would otherwise flood the logs during an outage - and
+/// instead emits a single warning when the circuit opens, staying quiet until a success closes it.
+struct ProcessingLoopBackoff {
+    consecutive_failures: u32,
+    circuit_open_logged: bool,
+}
+
+impl ProcessingLoopBackoff {
+    fn new() -> Self {
+        Self { consecutive_failures: 0, circuit_open_logged: false }
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.circuit_open_logged = false;
+    }
+
+    /// Records a failure and returns how long the loop should sleep before retrying.
+    fn record_failure(
+        &mut self,
+        loop_name: &str,
+        relayer_id: &RelayerId,
+        error: &impl std::fmt::Display,
+    ) -> Duration {
+        self.consecutive_failures += 1;
+
+        if self.consecutive_failures > CIRCUIT_OPEN_THRESHOLD {
+            if !self.circuit_open_logged {
+                warn!(
+                    "{} circuit open for relayer {} after {} consecutive failures, cooling down for {:?} before retrying: {}",
+                    loop_name, relayer_id, self.consecutive_failures, CIRCUIT_OPEN_COOLDOWN, error
+                );
+                self.circuit_open_logged = true;
+            }
+            return CIRCUIT_OPEN_COOLDOWN;
+        }
+
+        error!("{} ERROR for relayer {}: {}", loop_name, relayer_id, error);
+
+        let exponent = (self.consecutive_failures - 1).min(10);
+        let backoff = BACKOFF_BASE.saturating_mul(1 << exponent).min(BACKOFF_MAX);
+        let jitter_ms = thread_rng().gen_range(0..=(backoff.as_millis() as u64 / 2).max(1));
+        backoff + Duration::from_millis(jitter_ms)
+    }
+}
+
 /// Continuously processes pending transactions for a specific relayer.
 ///
 /// Runs in an infinite loop, processing one pending transaction at a time
@@ -71,8 +179,16 @@ async fn processes_next_break(process_again_after_ms: &u64) {
 async fn continuously_process_pending_transactions(
     queue: Arc<Mutex<TransactionsQueues>>,
     relayer_id: &RelayerId,
+    cancellation_token: CancellationToken,
 ) {
-    loop {
+    let notify = match queue.lock().await.get_transactions_queue(relayer_id) {
+        Some(transactions_queue) => transactions_queue.lock().await.notify_handles().pending,
+        None => return,
+    };
+
+    let mut backoff = ProcessingLoopBackoff::new();
+
+    while !cancellation_token.is_cancelled() {
         let result = {
             let mut tq = queue.lock().await;
             tq.process_single_pending(relayer_id).await
@@ -80,8 +196,16 @@ async fn continuously_process_pending_transactions(
 
         match result {
             Ok(result) => {
+                backoff.record_success();
                 // info!("PENDING: {:?}", result);
-                processes_next_break(&result.process_again_after).await;
+                // Wake up immediately on a NOTIFY for this relayer, but still fall back to the
+                // regular poll interval in case the notification was missed (e.g. across a
+                // listener reconnect).
+                tokio::select! {
+                    _ = notify.notified() => {}
+                    _ = processes_next_break(&result.process_again_after) => {}
+                    _ = cancellation_token.cancelled() => break,
+                }
             }
             Err(e) => {
                 match e {
@@ -93,8 +217,23 @@ async fn continuously_process_pending_transactions(
                         );
                         break;
                     }
+                    ProcessPendingTransactionError::SendTransactionError(
+                        TransactionQueueSendTransactionError::LeaseNoLongerHeld(_),
+                    ) => {
+                        // another node has reclaimed this relayer's lease - stop processing it
+                        // here rather than race that node's nonce manager
+                        info!(
+                            "Relayer id {} lease no longer held by this node, stopping the pending queue for it",
+                            relayer_id
+                        );
+                        break;
+                    }
                     _ => {
-                        error!("PENDING ERROR: {}", e)
+                        let delay = backoff.record_failure("PENDING", relayer_id, &e);
+                        tokio::select! {
+                            _ = sleep_ms(&(delay.as_millis() as u64)) => {}
+                            _ = cancellation_token.cancelled() => break,
+                        }
                     }
                 }
             }
@@ -109,8 +248,16 @@ async fn continuously_process_pending_transactions(
 async fn continuously_process_inmempool_transactions(
     queue: Arc<Mutex<TransactionsQueues>>,
     relayer_id: &RelayerId,
+    cancellation_token: CancellationToken,
 ) {
-    loop {
+    let notify = match queue.lock().await.get_transactions_queue(relayer_id) {
+        Some(transactions_queue) => transactions_queue.lock().await.notify_handles().inmempool,
+        None => return,
+    };
+
+    let mut backoff = ProcessingLoopBackoff::new();
+
+    while !cancellation_token.is_cancelled() {
         let result = {
             let mut tq = queue.lock().await;
             tq.process_single_inmempool(relayer_id).await
@@ -118,8 +265,13 @@ async fn continuously_process_inmempool_transactions(
 
         match result {
             Ok(result) => {
+                backoff.record_success();
                 // info!("INMEMPOOL: {:?}", result);
-                processes_next_break(&result.process_again_after).await;
+                tokio::select! {
+                    _ = notify.notified() => {}
+                    _ = processes_next_break(&result.process_again_after) => {}
+                    _ = cancellation_token.cancelled() => break,
+                }
             }
             Err(e) => {
                 match e {
@@ -131,8 +283,23 @@ async fn continuously_process_inmempool_transactions(
                         );
                         break;
                     }
+                    ProcessInmempoolTransactionError::SendTransactionError(
+                        TransactionQueueSendTransactionError::LeaseNoLongerHeld(_),
+                    ) => {
+                        // another node has reclaimed this relayer's lease - stop processing it
+                        // here rather than race that node's nonce manager
+                        info!(
+                            "Relayer id {} lease no longer held by this node, stopping the inmempool queue for it",
+                            relayer_id
+                        );
+                        break;
+                    }
                     _ => {
-                        error!("INMEMPOOL ERROR: {}", e)
+                        let delay = backoff.record_failure("INMEMPOOL", relayer_id, &e);
+                        tokio::select! {
+                            _ = sleep_ms(&(delay.as_millis() as u64)) => {}
+                            _ = cancellation_token.cancelled() => break,
+                        }
                     }
                 }
             }
@@ -147,8 +314,16 @@ async fn continuously_process_inmempool_transactions(
 async fn continuously_process_mined_transactions(
     queue: Arc<Mutex<TransactionsQueues>>,
     relayer_id: &RelayerId,
+    cancellation_token: CancellationToken,
 ) {
-    loop {
+    let notify = match queue.lock().await.get_transactions_queue(relayer_id) {
+        Some(transactions_queue) => transactions_queue.lock().await.notify_handles().mined,
+        None => return,
+    };
+
+    let mut backoff = ProcessingLoopBackoff::new();
+
+    while !cancellation_token.is_cancelled() {
         let result = {
             let mut tq = queue.lock().await;
             tq.process_single_mined(relayer_id).await
@@ -156,8 +331,13 @@ async fn continuously_process_mined_transactions(
 
         match result {
             Ok(result) => {
+                backoff.record_success();
                 // info!("MINED: {:?}", result);
-                processes_next_break(&result.process_again_after).await;
+                tokio::select! {
+                    _ = notify.notified() => {}
+                    _ = processes_next_break(&result.process_again_after) => {}
+                    _ = cancellation_token.cancelled() => break,
+                }
             }
             Err(e) => {
                 match e {
@@ -170,7 +350,11 @@ async fn continuously_process_mined_transactions(
                         break;
                     }
                     _ => {
-                        error!("MINED ERROR: {}", e)
+                        let delay = backoff.record_failure("MINED", relayer_id, &e);
+                        tokio::select! {
+                            _ = sleep_ms(&(delay.as_millis() as u64)) => {}
+                            _ = cancellation_token.cancelled() => break,
+                        }
                     }
                 }
             }
@@ -184,12 +368,22 @@ pub enum RepopulateTransactionsQueueError {
     CouldNotGetTransactionsByStatusFromDatabase(TransactionStatus, RelayerId, PostgresError),
 }
 
-/// Repopulates a transaction queue from the database for a specific status.
+/// Claims and repopulates a transaction queue from the database for a specific status.
 ///
-/// Loads all transactions with the given status for a relayer from the database,
-/// maintaining their nonce order in the queue.
+/// Claims every transaction with the given status for a relayer, stamping it as owned by
+/// `node_id`, and loads it into the queue maintaining nonce order. Safe to call without racing
+/// another node over the same rows, because by this point the relayer itself has already been
+/// claimed by `claim_relayers_for_node` - no other node's rebalance pass will try to claim this
+/// relayer's transactions at the same time.
+///
+/// Only ever called with `Pending`, `Inmempool`, or `Mined` - the working set a relayer actually
+/// needs to keep processing. Terminal transactions never show up here at all, and the retention
+/// subsystem (see [`super::retention`]) keeps them from piling up in `relayer.transaction` in the
+/// first place, so this stays bounded on a long-running relayer regardless of how much history
+/// it has accumulated.
 async fn repopulate_transaction_queue(
     db: &PostgresClient,
+    node_id: &NodeId,
     relayer_id: &RelayerId,
     status: &TransactionStatus,
 ) -> Result<VecDeque<Transaction>, RepopulateTransactionsQueueError> {
@@ -197,7 +391,7 @@ async fn repopulate_transaction_queue(
     let mut paging_context = PagingContext::new(1000, 0);
     loop {
         let results = db
-            .get_transactions_by_status_for_relayer(relayer_id, status, &paging_context)
+            .claim_transactions_by_status_for_relayer(node_id, relayer_id, status, &paging_context)
             .await
             .map_err(|e| {
                 RepopulateTransactionsQueueError::CouldNotGetTransactionsByStatusFromDatabase(
@@ -224,36 +418,146 @@ async fn repopulate_transaction_queue(
     Ok(transactions_queue)
 }
 
-/// Loads all relayers from the database.
-async fn load_relayers(db: &PostgresClient) -> Result<Vec<Relayer>, PostgresError> {
-    let mut relayers: Vec<Relayer> = Vec::new();
-    let mut paging_context = PagingContext::new(1000, 0);
+/// Claims every relayer available to `node_id` - unowned, or abandoned by a crashed node - up to
+/// `CLAIM_BATCH_SIZE` per pass, looping until a pass claims fewer than a full batch.
+async fn claim_all_available_relayers(
+    db: &PostgresClient,
+    node_id: &NodeId,
+) -> Result<Vec<(Relayer, i64)>, PostgresError> {
+    let mut relayers: Vec<(Relayer, i64)> = Vec::new();
     loop {
-        let results = db.get_relayers(&paging_context).await?;
+        let claimed = db.claim_relayers_for_node(node_id, DEFAULT_LEASE, CLAIM_BATCH_SIZE).await?;
+        let claimed_count = claimed.len();
 
-        let result_count = results.items.len();
+        relayers.extend(claimed);
 
-        for item in results.items {
-            relayers.push(item)
-        }
-
-        let next = paging_context.next(result_count);
-        match next {
-            Some(next) => paging_context = next,
-            None => break,
+        if claimed_count < CLAIM_BATCH_SIZE as usize {
+            break;
         }
     }
 
     Ok(relayers)
 }
 
+/// Builds the `TransactionRelayerSetup` for a relayer this node has already claimed, repopulating
+/// its pending, in-mempool, and mined queues from the database.
+async fn build_transaction_relayer_setup(
+    db: &PostgresClient,
+    node_id: &NodeId,
+    lease_epoch: i64,
+    relayer: Relayer,
+    evm_provider: EvmProvider,
+) -> Result<TransactionRelayerSetup, RepopulateTransactionsQueueError> {
+    let relayer_id = relayer.id;
+
+    let mined_transactions =
+        repopulate_transaction_queue(db, node_id, &relayer_id, &TransactionStatus::Mined).await?;
+
+    Ok(TransactionRelayerSetup::new(
+        relayer,
+        evm_provider,
+        repopulate_transaction_queue(db, node_id, &relayer_id, &TransactionStatus::Pending).await?,
+        repopulate_transaction_queue(db, node_id, &relayer_id, &TransactionStatus::Inmempool)
+            .await?,
+        mined_transactions.into_iter().map(|transaction| (transaction.id, transaction)).collect(),
+        Default::default(),
+        2,
+        NonceCap::default(),
+        1000,
+        *node_id,
+        lease_epoch,
+    ))
+}
+
+/// Spawns the task that periodically claims any relayer not currently owned by another live
+/// node - a newly created one, or one the reaper just freed from a crashed node - and starts its
+/// transaction queues on this node. This is what makes adding a node to the fleet rebalance
+/// automatically: idle relayers get picked up by whichever node's next pass sees them first.
+pub fn spawn_relayer_rebalance_task(
+    transactions_queues: Arc<Mutex<TransactionsQueues>>,
+    db: Arc<PostgresClient>,
+    node_id: NodeId,
+    providers: Arc<Vec<EvmProvider>>,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REBALANCE_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let claimed = match db.claim_relayers_for_node(&node_id, DEFAULT_LEASE, CLAIM_BATCH_SIZE).await {
+                Ok(relayers) => relayers,
+                Err(e) => {
+                    error!("Node {} failed to claim relayers during rebalance: {}", node_id, e);
+                    continue;
+                }
+            };
+
+            for (relayer, lease_epoch) in claimed {
+                let relayer_id = relayer.id;
+
+                let already_running =
+                    transactions_queues.lock().await.get_transactions_queue(&relayer_id).is_some();
+                if already_running {
+                    // This node already had the relayer running (its own heartbeat kept the
+                    // lease alive) - nothing to do.
+                    continue;
+                }
+
+                let evm_provider = match find_provider_for_chain_id(&providers, &relayer.chain_id).await {
+                    Some(provider) => provider.clone(),
+                    None => {
+                        warn!(
+                            "Node {} claimed relayer {} on chain {} but no provider is configured for that chain, leaving it unstarted",
+                            node_id, relayer_id, relayer.chain_id
+                        );
+                        continue;
+                    }
+                };
+
+                let setup = match build_transaction_relayer_setup(
+                    &db,
+                    &node_id,
+                    lease_epoch,
+                    relayer,
+                    evm_provider,
+                )
+                .await
+                {
+                    Ok(setup) => setup,
+                    Err(e) => {
+                        error!(
+                            "Node {} failed to repopulate queues for newly claimed relayer {}: {}",
+                            node_id, relayer_id, e
+                        );
+                        continue;
+                    }
+                };
+
+                let add_result = transactions_queues
+                    .lock()
+                    .await
+                    .add_claimed_relayer(setup, transactions_queues.clone())
+                    .await;
+
+                match add_result {
+                    Ok(()) => info!("Node {} claimed and started relayer {}", node_id, relayer_id),
+                    Err(e) => error!(
+                        "Node {} failed to start queues for newly claimed relayer {}: {}",
+                        node_id, relayer_id, e
+                    ),
+                }
+            }
+        }
+    });
+}
+
 #[derive(Error, Debug)]
 pub enum StartTransactionsQueuesError {
     #[error("Failed to connect to the database: {0}")]
     DatabaseConnectionError(PostgresConnectionError),
 
-    #[error("Failed to load relayers from database: {0}")]
-    CouldNotLoadRelayersFromDatabase(PostgresError),
+    #[error("Failed to claim relayers from database: {0}")]
+    CouldNotLoadRelayersFromDatabase(#[from] PostgresError),
 
     #[error("Failed to repopulate transactions queue: {0}")]
     RepopulateTransactionsQueueError(#[from] RepopulateTransactionsQueueError),
@@ -274,19 +578,26 @@ pub async fn startup_transactions_queues(
     cache: Arc<Cache>,
     webhook_manager: Option<Arc<Mutex<WebhookManager>>>,
     safe_proxy_manager: Arc<SafeProxyManager>,
-) -> Result<Arc<Mutex<TransactionsQueues>>, StartTransactionsQueuesError> {
+    transaction_retention: Option<TransactionRetentionConfig>,
+) -> Result<(Arc<Mutex<TransactionsQueues>>, RelayerTaskRegistry, NodeId), StartTransactionsQueuesError>
+{
     let postgres = PostgresClient::new()
         .await
         .map_err(StartTransactionsQueuesError::DatabaseConnectionError)?;
 
-    // has to load them ALL to populate their queues
-    let relays = load_relayers(&postgres)
-        .await
-        .map_err(StartTransactionsQueuesError::CouldNotLoadRelayersFromDatabase)?;
+    // Every node gets its own identity on each start, so a restart simply looks like a fresh node
+    // claiming whatever is available - its own previous lease having long since expired.
+    let node_id = NodeId::new();
+    info!("Starting transaction queues as node {}", node_id);
+
+    // Claim every relayer available to this node up front, so this node's queues start out with
+    // as much of the fleet as possible instead of waiting on the rebalance task's first tick.
+    let relays = claim_all_available_relayers(&postgres, &node_id).await?;
 
     let mut transaction_relayer_setups: Vec<TransactionRelayerSetup> = Vec::new();
+    let mut transaction_relayer_setup_relayer_ids: Vec<RelayerId> = Vec::new();
 
-    for relayer in relays {
+    for (relayer, lease_epoch) in relays {
         let provider = find_provider_for_chain_id(&providers, &relayer.chain_id).await;
 
         match provider {
@@ -296,33 +607,19 @@ pub async fn startup_transactions_queues(
             }
             Some(provider) => {
                 let evm_provider = provider.clone();
-
                 let relayer_id = relayer.id;
 
-                let mined_transactions =
-                    repopulate_transaction_queue(&postgres, &relayer_id, &TransactionStatus::Mined)
-                        .await?;
-
-                transaction_relayer_setups.push(TransactionRelayerSetup::new(
-                    relayer,
-                    evm_provider,
-                    repopulate_transaction_queue(
-                        &postgres,
-                        &relayer_id,
-                        &TransactionStatus::Pending,
-                    )
-                    .await?,
-                    repopulate_transaction_queue(
+                transaction_relayer_setups.push(
+                    build_transaction_relayer_setup(
                         &postgres,
-                        &relayer_id,
-                        &TransactionStatus::Inmempool,
+                        &node_id,
+                        lease_epoch,
+                        relayer,
+                        evm_provider,
                     )
                     .await?,
-                    mined_transactions
-                        .into_iter()
-                        .map(|transaction| (transaction.id, transaction))
-                        .collect(),
-                ));
+                );
+                transaction_relayer_setup_relayer_ids.push(relayer_id);
             }
         }
     }
@@ -339,7 +636,25 @@ pub async fn startup_transactions_queues(
         .await?,
     ));
 
+    let postgres = Arc::new(postgres);
+
+    spawn_relayer_notification_listener(transactions_queues.clone()).await;
     spawn_processing_tasks(transactions_queues.clone()).await;
+    spawn_lease_heartbeat(postgres.clone(), node_id);
+    spawn_lease_reaper(postgres.clone(), DEFAULT_LEASE);
+
+    for relayer_id in transaction_relayer_setup_relayer_ids {
+        spawn_scheduled_transaction_ticker(postgres.clone(), transactions_queues.clone(), relayer_id);
+        spawn_stuck_transaction_rescue_ticker(transactions_queues.clone(), relayer_id);
+    }
+
+    if let Some(transaction_retention) = transaction_retention {
+        spawn_transaction_retention_task(postgres.clone(), transaction_retention);
+    }
+
+    spawn_relayer_rebalance_task(transactions_queues.clone(), postgres, node_id, providers);
+
+    let relayer_task_registry = transactions_queues.lock().await.relayer_task_registry();
 
-    Ok(transactions_queues)
+    Ok((transactions_queues, relayer_task_registry, node_id))
 }
@@ -0,0 +1,52 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+use super::transactions_queues::TransactionsQueues;
+use crate::relayer::RelayerId;
+
+/// How often a relayer's ticker checks whether the transaction at the front of its inmempool
+/// queue is `FEECAPPED` and was last broadcast long enough ago to need rescuing.
+const STUCK_TRANSACTION_RESCUE_TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Spawns the ticker that periodically checks a relayer's inmempool queue for a transaction
+/// stuck `FEECAPPED` at the front - blocking every nonce behind it - and rescues it once it has
+/// sat there too long. Runs forever, stopping only when the relayer's queue is gone (deleted, or
+/// owned by another node after a rebalance).
+pub fn spawn_stuck_transaction_rescue_ticker(
+    transactions_queues: Arc<Mutex<TransactionsQueues>>,
+    relayer_id: RelayerId,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(STUCK_TRANSACTION_RESCUE_TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let still_running =
+                transactions_queues.lock().await.get_transactions_queue(&relayer_id).is_some();
+            if !still_running {
+                break;
+            }
+
+            let rescued =
+                transactions_queues.lock().await.rescue_stuck_transaction(&relayer_id).await;
+
+            match rescued {
+                Ok(Some(transaction)) => {
+                    info!(
+                        "Rescued transaction {} stuck FEECAPPED for relayer {}",
+                        transaction.id, relayer_id
+                    );
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    error!(
+                        "Failed to check/rescue stuck transaction for relayer {}: {}",
+                        relayer_id, e
+                    );
+                }
+            }
+        }
+    });
+}
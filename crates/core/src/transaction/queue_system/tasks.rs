@@ -0,0 +1,74 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::{sync::Mutex, task::JoinHandle};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::relayer::RelayerId;
+
+/// The cancellation token and join handles for the three processing loops (pending, inmempool,
+/// mined) spawned for a single relayer.
+struct RelayerTasks {
+    cancellation_token: CancellationToken,
+    pending: JoinHandle<()>,
+    inmempool: JoinHandle<()>,
+    mined: JoinHandle<()>,
+}
+
+/// Registry of the processing tasks spawned per relayer, so a relayer's tasks can be torn down
+/// deterministically - either individually, when the relayer is removed, or all together, on
+/// process shutdown - instead of relying on each loop eventually observing a
+/// `RelayerTransactionsQueueNotFound` error.
+#[derive(Clone, Default)]
+pub struct RelayerTaskRegistry {
+    tasks: Arc<Mutex<HashMap<RelayerId, RelayerTasks>>>,
+}
+
+impl RelayerTaskRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the cancellation token and join handles for a relayer's processing tasks. Any
+    /// previously registered tasks for the same id are left running and simply forgotten - callers
+    /// are expected to `stop` a relayer's old tasks before registering new ones for it.
+    pub async fn register(
+        &self,
+        relayer_id: RelayerId,
+        cancellation_token: CancellationToken,
+        pending: JoinHandle<()>,
+        inmempool: JoinHandle<()>,
+        mined: JoinHandle<()>,
+    ) {
+        self.tasks
+            .lock()
+            .await
+            .insert(relayer_id, RelayerTasks { cancellation_token, pending, inmempool, mined });
+    }
+
+    /// Cancels and removes a single relayer's processing tasks, awaiting their handles so any
+    /// in-flight database transaction completes before returning. Does nothing if the relayer has
+    /// no registered tasks.
+    pub async fn stop(&self, relayer_id: &RelayerId) {
+        let tasks = self.tasks.lock().await.remove(relayer_id);
+
+        if let Some(tasks) = tasks {
+            tasks.cancellation_token.cancel();
+            let _ = tokio::join!(tasks.pending, tasks.inmempool, tasks.mined);
+            info!("Stopped processing tasks for relayer {}", relayer_id);
+        }
+    }
+
+    /// Cancels every registered relayer's processing tasks and awaits all their handles, so
+    /// in-flight database transactions complete before the process exits.
+    pub async fn shutdown(&self) {
+        let all_tasks: Vec<(RelayerId, RelayerTasks)> = self.tasks.lock().await.drain().collect();
+
+        info!("Shutting down processing tasks for {} relayer(s)", all_tasks.len());
+
+        for (_, tasks) in all_tasks {
+            tasks.cancellation_token.cancel();
+            let _ = tokio::join!(tasks.pending, tasks.inmempool, tasks.mined);
+        }
+    }
+}
@@ -4,33 +4,44 @@ use std::{
     time::{Duration, SystemTime},
 };
 
+use super::notify::RelayerQueueNotify;
 use super::types::{
-    EditableTransaction, MoveInmempoolTransactionToMinedError,
-    MovePendingTransactionToInmempoolError, SendTransactionGasPriceError,
-    TransactionQueueSendTransactionError, TransactionSentWithRelayer, TransactionsQueueSetup,
+    CanonicalCheck, EditableTransaction, HeaderChainTracker, MoveInmempoolTransactionToMinedError,
+    MovePendingTransactionToInmempoolError, NodeId, NonceCap, PendingQueueFullError,
+    SendTransactionGasPriceError, TrackedBlockHeader, TransactionQueueSendTransactionError,
+    TransactionScore, TransactionSentWithRelayer, TransactionsQueueSetup,
 };
 use crate::relayer::types::RelayerId;
 use crate::transaction::types::{TransactionNonce, TransactionValue};
 use crate::{
     gas::{
         blob_gas_oracle::{BlobGasOracleCache, BlobGasPriceResult, BLOB_GAS_PER_BLOB},
+        calculate_l1_data_fee,
         fee_estimator::base::GasPriceResult,
         gas_oracle::GasOracleCache,
-        types::{GasLimit, GasPrice},
+        is_op_stack_chain,
+        types::{GasLimit, GasPrice, MaxFee, MaxPriorityFee},
     },
     network::types::ChainId,
     postgres::PostgresClient,
     provider::EvmProvider,
     relayer::types::Relayer,
+    relayer::{OnchainAllowlistCache, OnchainAllowlistError},
     safe_proxy::SafeProxyManager,
-    shared::common_types::EvmAddress,
+    shared::common_types::{BlockHash, BlockNumber, EvmAddress},
     transaction::types::TransactionData,
     transaction::{
         nonce_manager::NonceManager,
-        types::{Transaction, TransactionHash, TransactionId, TransactionSpeed, TransactionStatus},
+        types::{
+            Transaction, TransactionEnvelopeType, TransactionHash, TransactionId,
+            TransactionSpeed, TransactionStatus,
+        },
     },
+    yaml::GasBumpBlockConfig,
 };
+use alloy::eips::eip2930::AccessList;
 use alloy::network::{AnyTransactionReceipt, ReceiptResponse};
+use alloy::primitives::B256;
 use alloy::{
     consensus::{SignableTransaction, TypedTransaction},
     hex,
@@ -60,8 +71,29 @@ pub struct TransactionsQueue {
     blob_oracle_cache: Arc<Mutex<BlobGasOracleCache>>,
     confirmations: u64,
     safe_proxy_manager: Option<SafeProxyManager>,
+    gas_bump_config: GasBumpBlockConfig,
+    max_gas_price_multiplier: u64,
+    nonce_cap: NonceCap,
+    per_relayer_max_inflight: usize,
+    /// Wakeup signals for the three processing loops in `start.rs`, fired by the relayer
+    /// notification listener when Postgres delivers a `NOTIFY rrelayer_tx_<relayer_id>`.
+    notify: RelayerQueueNotify,
+    /// Rolling window of recently observed block headers for this relayer's network, used to
+    /// detect when a mined transaction's block has been reorged away instead of just assuming
+    /// the chain grew linearly past the required confirmation depth.
+    header_tracker: Mutex<HeaderChainTracker>,
+    /// This node's identity and the fencing token it was handed when it claimed `relayer`.
+    /// Checked against `relayer.record` immediately before broadcasting so a node whose lease
+    /// was silently reclaimed by another node aborts instead of racing the new owner.
+    node_id: NodeId,
+    lease_epoch: i64,
 }
 
+/// How many recent block headers the reorg tracker keeps per relayer. Comfortably larger than
+/// any reasonable confirmation depth, so a tracked mined block doesn't fall out of the window
+/// before it has had a chance to accumulate confirmations.
+const HEADER_TRACKER_WINDOW: usize = 256;
+
 impl TransactionsQueue {
     /// Creates a new TransactionsQueue for a specific relayer.
     ///
@@ -78,8 +110,11 @@ impl TransactionsQueue {
         blob_oracle_cache: Arc<Mutex<BlobGasOracleCache>>,
     ) -> Self {
         info!(
-            "Creating new TransactionsQueue for relayer: {} (name: {}) on chain: {}",
-            setup.relayer.id, setup.relayer.name, setup.relayer.chain_id
+            "Creating new TransactionsQueue for relayer: {} (name: {}) on chain: {} (node client: {:?})",
+            setup.relayer.id,
+            setup.relayer.name,
+            setup.relayer.chain_id,
+            setup.evm_provider.node_client()
         );
         let confirmations = setup.evm_provider.confirmations;
         Self {
@@ -93,9 +128,23 @@ impl TransactionsQueue {
             blob_oracle_cache,
             confirmations,
             safe_proxy_manager: setup.safe_proxy_manager,
+            gas_bump_config: setup.gas_bump_config,
+            max_gas_price_multiplier: setup.max_gas_price_multiplier,
+            nonce_cap: setup.nonce_cap,
+            per_relayer_max_inflight: setup.per_relayer_max_inflight,
+            notify: RelayerQueueNotify::new(),
+            header_tracker: Mutex::new(HeaderChainTracker::new(HEADER_TRACKER_WINDOW)),
+            node_id: setup.node_id,
+            lease_epoch: setup.lease_epoch,
         }
     }
 
+    /// Returns the wakeup handles the relayer notification listener fires into when Postgres
+    /// delivers a NOTIFY for this relayer.
+    pub fn notify_handles(&self) -> RelayerQueueNotify {
+        self.notify.clone()
+    }
+
     /// Returns the number of blocks to wait before bumping gas price based on transaction speed.
     ///
     /// # Arguments
@@ -104,12 +153,7 @@ impl TransactionsQueue {
     /// # Returns
     /// * `u64` - Number of blocks to wait before gas price bump
     fn blocks_to_wait_before_bump(&self, speed: &TransactionSpeed) -> u64 {
-        match speed {
-            TransactionSpeed::Slow => 10,
-            TransactionSpeed::Medium => 5,
-            TransactionSpeed::Fast => 4,
-            TransactionSpeed::Super => 2,
-        }
+        self.gas_bump_config.blocks_to_wait_before_bump(speed)
     }
 
     /// Determines if gas price should be bumped based on elapsed time and transaction speed.
@@ -153,6 +197,83 @@ impl TransactionsQueue {
         );
     }
 
+    /// Decides whether `candidate` may be admitted to the pending queue, without pushing it.
+    ///
+    /// Checked in two stages:
+    /// - `NonceCap`: a hard ceiling on how many not-yet-sent (future) nonces this relayer may
+    ///   hold at once. Reaching it always rejects, regardless of score.
+    /// - Per-relayer in-flight cap: once `pending + inmempool` reaches
+    ///   `per_relayer_max_inflight`, `candidate` is only admitted if its `TransactionScore`
+    ///   beats the weakest transaction currently in the pending queue, which is evicted to make
+    ///   room. Inmempool transactions are never evicted since they've already been broadcast.
+    ///
+    /// Takes just the candidate's id and pre-computed `TransactionScore` rather than a full
+    /// `Transaction`, so callers can run admission before assigning a nonce or building the rest
+    /// of the `Transaction` struct - there's no point consuming either if the candidate is going
+    /// to be rejected.
+    ///
+    /// # Returns
+    /// * `Ok(None)` - Room was available; the caller should push `candidate` as usual
+    /// * `Ok(Some(evicted))` - `evicted` was bumped from the pending queue to make room
+    /// * `Err(PendingQueueFullError)` - No room, and `candidate` didn't outscore the weakest entry
+    pub async fn try_admit_pending_transaction(
+        &self,
+        candidate_id: &TransactionId,
+        candidate_score: TransactionScore,
+    ) -> Result<Option<Transaction>, PendingQueueFullError> {
+        let mut pending = self.pending_transactions.lock().await;
+
+        if !self.nonce_cap.allows(pending.len()) {
+            info!(
+                "Nonce cap reached for relayer: {} ({} future nonces already queued) - rejecting transaction {}",
+                self.relayer.name, pending.len(), candidate_id
+            );
+            return Err(PendingQueueFullError(self.relayer.id));
+        }
+
+        let inmempool_count = self.inmempool_transactions.lock().await.len();
+        if pending.len() + inmempool_count < self.per_relayer_max_inflight {
+            return Ok(None);
+        }
+
+        let weakest = pending
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, tx)| TransactionScore::of(tx))
+            .map(|(index, tx)| (index, TransactionScore::of(tx)));
+
+        match weakest {
+            Some((index, weakest_score)) if candidate_score > weakest_score => {
+                let evicted = pending.remove(index).expect("index came from this queue");
+                info!(
+                    "Per-relayer in-flight cap reached for relayer: {} - evicting transaction {} to admit {}",
+                    self.relayer.name, evicted.id, candidate_id
+                );
+                Ok(Some(evicted))
+            }
+            _ => {
+                info!(
+                    "Per-relayer in-flight cap reached for relayer: {} - rejecting transaction {} (does not outscore the weakest queued transaction)",
+                    self.relayer.name, candidate_id
+                );
+                Err(PendingQueueFullError(self.relayer.id))
+            }
+        }
+    }
+
+    /// Number of transactions ready to be broadcast immediately, i.e. already sent and awaiting
+    /// mining. Since this relayer assigns its own nonces sequentially (see `NonceManager`),
+    /// there's never a nonce gap to wait on - "ready" maps directly onto the inmempool queue.
+    pub async fn ready_transaction_count(&self) -> usize {
+        self.get_inmempool_transaction_count().await
+    }
+
+    /// Number of transactions still waiting on a future nonce to be sent, i.e. queued but not
+    /// yet broadcast.
+    pub async fn future_transaction_count(&self) -> usize {
+        self.get_pending_transaction_count().await
+    }
+
     /// Gets the next pending transaction without removing it from the queue.
     ///
     /// # Returns
@@ -358,6 +479,27 @@ impl TransactionsQueue {
         }
     }
 
+    /// Updates the status of the front inmempool transaction in place, without touching any of
+    /// its other fields. Used to reflect a status change that was already persisted to the
+    /// database (e.g. the escalator marking a transaction `FEECAPPED`, or a rescue bringing one
+    /// back to `INMEMPOOL`) in the in-memory queue the processing loop actually reads from.
+    ///
+    /// # Arguments
+    /// * `transaction_id` - The ID of the transaction to update
+    /// * `status` - The new status to apply
+    pub async fn update_inmempool_transaction_status(
+        &mut self,
+        transaction_id: &TransactionId,
+        status: TransactionStatus,
+    ) {
+        let mut transactions = self.inmempool_transactions.lock().await;
+        if let Some(transaction) = transactions.front_mut() {
+            if transaction.id == *transaction_id {
+                transaction.status = status;
+            }
+        }
+    }
+
     /// Updates the inmempool transaction with no-op details after cancellation.
     ///
     /// # Arguments
@@ -475,12 +617,24 @@ impl TransactionsQueue {
                     info!("Transaction {} failed on-chain for relayer: {}", id, self.relayer.name);
                 }
 
+                let gas_used = Some(GasLimit::from(receipt.gas_used));
+                let effective_gas_price =
+                    Some(transaction.effective_gas_price_from_receipt(receipt));
+                let reverted = Some(!receipt.status());
+                let mined_at_block_number = receipt.block_number.map(BlockNumber::new);
+                let mined_at_block_hash = receipt.block_hash.map(BlockHash::new);
+
                 let mut mining_transactions = self.mined_transactions.lock().await;
                 mining_transactions.insert(
                     transaction.id,
                     Transaction {
                         status: transaction_status.clone(),
                         mined_at: Some(Utc::now()),
+                        gas_used,
+                        effective_gas_price,
+                        reverted,
+                        mined_at_block_number,
+                        mined_at_block_hash,
                         ..transaction
                     },
                 );
@@ -545,6 +699,45 @@ impl TransactionsQueue {
         );
     }
 
+    /// Moves a transaction from the mined map back to the front of the inmempool queue, because
+    /// the block it was mined in was reorged off the canonical chain. Clears its mined-related
+    /// fields so it's tracked exactly as it was before being mined, and resumes from there - the
+    /// inmempool processing loop picks it back up and waits for it to land (in the same or a
+    /// different block) again.
+    ///
+    /// # Arguments
+    /// * `id` - The transaction ID to roll back
+    ///
+    /// # Returns
+    /// * `Some(Transaction)` - The rolled-back transaction, now at the front of the inmempool queue
+    /// * `None` - If the transaction was not found in the mined map
+    pub async fn move_mined_to_reorged(&mut self, id: &TransactionId) -> Option<Transaction> {
+        let mut mining_transactions = self.mined_transactions.lock().await;
+        let transaction = mining_transactions.remove(id)?;
+        drop(mining_transactions);
+
+        info!(
+            "Transaction {} reorged out of its mined block, rolling back to inmempool for relayer: {}",
+            id, self.relayer.name
+        );
+
+        let reorged_transaction = Transaction {
+            status: TransactionStatus::Inmempool,
+            mined_at: None,
+            mined_at_block_number: None,
+            mined_at_block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            reverted: None,
+            ..transaction
+        };
+
+        let mut transactions = self.inmempool_transactions.lock().await;
+        transactions.push_front(reorged_transaction.clone());
+
+        Some(reorged_transaction)
+    }
+
     /// Returns the relayer's wallet address.
     ///
     /// # Returns
@@ -584,6 +777,69 @@ impl TransactionsQueue {
         self.relayer.eip_1559_enabled = is_legacy_transactions;
     }
 
+    /// Returns the typed-transaction envelope this relayer builds and signs with.
+    ///
+    /// # Returns
+    /// * `TransactionEnvelopeType` - The relayer's preferred envelope
+    pub fn preferred_envelope(&self) -> TransactionEnvelopeType {
+        self.relayer.preferred_envelope.clone()
+    }
+
+    /// Sets the typed-transaction envelope this relayer builds and signs with.
+    ///
+    /// # Arguments
+    /// * `preferred_envelope` - The new preferred envelope
+    pub fn set_preferred_envelope(&mut self, preferred_envelope: TransactionEnvelopeType) {
+        info!(
+            "Setting preferred envelope to {} for relayer: {}",
+            preferred_envelope, self.relayer.name
+        );
+        self.relayer.preferred_envelope = preferred_envelope;
+    }
+
+    /// Returns the access list attached to outgoing transactions when `preferred_envelope` is
+    /// `EIP2930`.
+    ///
+    /// # Returns
+    /// * `Some(AccessList)` - The default access list if configured
+    /// * `None` - If no default access list is configured
+    pub fn default_access_list(&self) -> Option<AccessList> {
+        self.relayer.default_access_list.clone()
+    }
+
+    /// Sets the access list attached to outgoing transactions when `preferred_envelope` is
+    /// `EIP2930`.
+    ///
+    /// # Arguments
+    /// * `default_access_list` - The new default access list, or None to clear it
+    pub fn set_default_access_list(&mut self, default_access_list: Option<AccessList>) {
+        info!(
+            "Setting default access list to {:?} for relayer: {}",
+            default_access_list, self.relayer.name
+        );
+        self.relayer.default_access_list = default_access_list;
+    }
+
+    /// Whether a transaction without its own access list should be run through
+    /// `eth_createAccessList` before broadcast, keeping the suggested list only when it lowers
+    /// total gas.
+    pub fn auto_access_list(&self) -> bool {
+        self.relayer.auto_access_list
+    }
+
+    /// Sets whether outgoing transactions without their own access list should have one
+    /// generated automatically via `eth_createAccessList`.
+    ///
+    /// # Arguments
+    /// * `auto_access_list` - True to opt in to automatic access-list generation
+    pub fn set_auto_access_list(&mut self, auto_access_list: bool) {
+        info!(
+            "Setting auto access list to {} for relayer: {}",
+            auto_access_list, self.relayer.name
+        );
+        self.relayer.auto_access_list = auto_access_list;
+    }
+
     /// Checks if this relayer only accepts transactions from allowlisted addresses.
     ///
     /// # Returns
@@ -667,6 +923,72 @@ impl TransactionsQueue {
         self.relayer.chain_id
     }
 
+    /// Returns the on-chain allowlist contract address consulted by `refuse_service`, if set.
+    ///
+    /// # Returns
+    /// * `Some(EvmAddress)` - The allowlist contract address if configured
+    /// * `None` - If no allowlist contract is configured
+    pub fn whitelist_contract_address(&self) -> Option<EvmAddress> {
+        self.relayer.whitelist_contract_address
+    }
+
+    /// Sets the on-chain allowlist contract address consulted by `refuse_service`.
+    ///
+    /// # Arguments
+    /// * `whitelist_contract_address` - The new allowlist contract address, or None to clear it
+    pub fn set_whitelist_contract_address(
+        &mut self,
+        whitelist_contract_address: Option<EvmAddress>,
+    ) {
+        info!(
+            "Setting whitelist contract address to {:?} for relayer: {}",
+            whitelist_contract_address, self.relayer.name
+        );
+        self.relayer.whitelist_contract_address = whitelist_contract_address;
+    }
+
+    /// Checks if this relayer refuses to send to recipients not certified by its allowlist
+    /// contract.
+    ///
+    /// # Returns
+    /// * `bool` - True if the relayer only sends to certified recipients
+    pub fn is_refuse_service(&self) -> bool {
+        self.relayer.refuse_service
+    }
+
+    /// Sets whether this relayer should refuse to send to recipients not certified by its
+    /// allowlist contract.
+    ///
+    /// # Arguments
+    /// * `refuse_service` - True to only allow certified recipients
+    pub fn set_refuse_service(&mut self, refuse_service: bool) {
+        info!("Setting refuse service to {} for relayer: {}", refuse_service, self.relayer.name);
+        self.relayer.refuse_service = refuse_service;
+    }
+
+    /// Checks whether `to` is allowed to receive a transaction from this relayer under its
+    /// `refuse_service` policy.
+    ///
+    /// Returns `Ok(true)` immediately if `refuse_service` is disabled or no allowlist contract is
+    /// configured - `refuse_service` has no effect until both are set.
+    pub async fn is_recipient_certified(
+        &self,
+        onchain_allowlist_cache: &OnchainAllowlistCache,
+        to: &EvmAddress,
+    ) -> Result<bool, OnchainAllowlistError> {
+        if !self.relayer.refuse_service {
+            return Ok(true);
+        }
+
+        let Some(contract_address) = self.relayer.whitelist_contract_address else {
+            return Ok(true);
+        };
+
+        onchain_allowlist_cache
+            .is_certified(&self.evm_provider.rpc_client(), &contract_address, to)
+            .await
+    }
+
     /// Checks if the proposed gas price is within configured bounds.
     ///
     /// Compares the gas price against the relayer's maximum limit to prevent
@@ -704,6 +1026,41 @@ impl TransactionsQueue {
         true
     }
 
+    /// Multiplies a fee component by 1.125, computed in integer arithmetic on the underlying
+    /// wei value so the escalator never rounds below the 10% node-replacement floor.
+    fn bump_by_12_5_percent(value: u128) -> u128 {
+        value + (value * 125) / 1000
+    }
+
+    /// Checks whether `transaction` is still allowed to be escalated again, against its own
+    /// per-transaction resubmission ceiling.
+    ///
+    /// This is separate from `within_gas_price_bounds`, which enforces the relayer-wide
+    /// `max_gas_price`: this enforces the caller-supplied `max_resubmissions` count and
+    /// `max_fee_cap` set on the transaction itself, so escalation stops rather than overpaying
+    /// indefinitely even when no relayer-wide cap is configured.
+    pub fn can_escalate(&self, transaction: &Transaction, next_gas_price: &GasPriceResult) -> bool {
+        if let Some(max_resubmissions) = transaction.max_resubmissions {
+            if transaction.resubmission_count >= max_resubmissions {
+                return false;
+            }
+        }
+
+        if let Some(max_fee_cap) = &transaction.max_fee_cap {
+            let proposed = if self.relayer.eip_1559_enabled {
+                next_gas_price.max_fee.into_u128()
+            } else {
+                next_gas_price.legacy_gas_price().into_u128()
+            };
+
+            if proposed > max_fee_cap.into_u128() {
+                return false;
+            }
+        }
+
+        true
+    }
+
     /// Returns the average block time in milliseconds for this blockchain.
     ///
     /// Used for timing calculations like confirmation waits and gas price bumping.
@@ -736,6 +1093,60 @@ impl TransactionsQueue {
         in_range
     }
 
+    /// Checks whether a transaction's mined block is still part of the canonical chain, and how
+    /// many confirmations it has if so.
+    ///
+    /// Fetches the current chain head, records it in the rolling header window, then resolves
+    /// the canonical hash at `block_number` - from the window if it's still within
+    /// [`HEADER_TRACKER_WINDOW`] of the head, otherwise with a direct RPC lookup - and compares
+    /// it against `block_hash`. A mismatch means the tracked block was reorged away.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block number the transaction was mined in
+    /// * `block_hash` - The hash of that block at the time the transaction was mined
+    pub async fn check_mined_block_confirmation(
+        &self,
+        block_number: u64,
+        block_hash: B256,
+    ) -> Result<CanonicalCheck, RpcError<TransportErrorKind>> {
+        let head = self.evm_provider.get_latest_block_header().await?;
+
+        let mut tracker = self.header_tracker.lock().await;
+        tracker.record(TrackedBlockHeader {
+            number: head.number,
+            hash: head.hash,
+            parent_hash: head.parent_hash,
+        });
+
+        if let Some(check) = tracker.check(block_number, block_hash, head.number) {
+            return Ok(check);
+        }
+
+        // The mined block fell outside the rolling window (or we only just started tracking
+        // this network) - ask the node directly what's canonical at that height.
+        let canonical = self
+            .evm_provider
+            .get_block_header(BlockId::Number(BlockNumberOrTag::Number(block_number)))
+            .await?;
+
+        tracker.record(match canonical {
+            Some(header) => TrackedBlockHeader {
+                number: header.number,
+                hash: header.hash,
+                parent_hash: header.parent_hash,
+            },
+            None => {
+                // No block at that height at all yet from this node's point of view - treat it
+                // as not confirmed rather than reorged, since we have nothing to compare against.
+                return Ok(CanonicalCheck::StillCanonical { confirmations: 0 });
+            }
+        });
+
+        Ok(tracker
+            .check(block_number, block_hash, head.number)
+            .unwrap_or(CanonicalCheck::StillCanonical { confirmations: 0 }))
+    }
+
     /// Computes the appropriate gas price for a transaction based on speed tier.
     ///
     /// Queries the gas oracle for current network conditions and applies speed-based
@@ -769,9 +1180,14 @@ impl TransactionsQueue {
             info!("Adjusting gas price based on previous attempt for relayer: {}. Previous max_fee: {}, max_priority_fee: {}",
                 self.relayer.name, sent_gas.max_fee.into_u128(), sent_gas.max_priority_fee.into_u128());
 
-            if gas_price.max_fee <= sent_gas.max_fee {
+            // The node replacement rule only requires a 10% bump over the last broadcast value;
+            // we use 12.5% so rounding never leaves a resubmission right on the edge of being
+            // rejected as an underpriced replacement. This is a floor, not a target: a fresh
+            // estimate that already clears it (e.g. the network got more congested) is kept.
+            let min_bumped_max_fee = Self::bump_by_12_5_percent(sent_gas.max_fee.into_u128());
+            if gas_price.max_fee.into_u128() < min_bumped_max_fee {
                 let old_max_fee = gas_price.max_fee;
-                gas_price.max_fee = sent_gas.max_fee + (sent_gas.max_fee / 10);
+                gas_price.max_fee = MaxFee::new(min_bumped_max_fee);
                 info!(
                     "Bumped max_fee for relayer: {} from {} to {}",
                     self.relayer.name,
@@ -780,10 +1196,11 @@ impl TransactionsQueue {
                 );
             }
 
-            if gas_price.max_priority_fee <= sent_gas.max_priority_fee {
+            let min_bumped_priority_fee =
+                Self::bump_by_12_5_percent(sent_gas.max_priority_fee.into_u128());
+            if gas_price.max_priority_fee.into_u128() < min_bumped_priority_fee {
                 let old_priority_fee = gas_price.max_priority_fee;
-                gas_price.max_priority_fee =
-                    sent_gas.max_priority_fee + (sent_gas.max_priority_fee / 10);
+                gas_price.max_priority_fee = MaxPriorityFee::new(min_bumped_priority_fee);
                 info!(
                     "Bumped max_priority_fee for relayer: {} from {} to {}",
                     self.relayer.name,
@@ -803,6 +1220,23 @@ impl TransactionsQueue {
         Ok(gas_price)
     }
 
+    /// Computes the L1 data-fee contribution for a transaction's calldata, for relayers on
+    /// OP-Stack L2s where this can dwarf the L2 execution fee. Returns `None` on every other
+    /// network, where there is no `GasPriceOracle` predeploy to consult.
+    pub async fn compute_l1_data_fee_for_transaction(
+        &self,
+        calldata: &[u8],
+    ) -> Result<Option<u128>, SendTransactionGasPriceError> {
+        if !is_op_stack_chain(&self.relayer.chain_id) {
+            return Ok(None);
+        }
+
+        let l1_data_fee =
+            calculate_l1_data_fee(self.evm_provider.rpc_client().as_ref(), calldata).await?;
+
+        Ok(Some(l1_data_fee))
+    }
+
     /// Computes the appropriate blob gas price for EIP-4844 blob transactions.
     ///
     /// Queries the blob gas oracle for current blob space pricing and applies
@@ -985,6 +1419,14 @@ impl TransactionsQueue {
 
         info!("Sending transaction {:?} for relayer: {}", transaction, self.relayer.name);
 
+        // This node may have been sitting on a lease that has since expired and been handed to
+        // another node by the reaper (a delayed heartbeat, a GC pause). Re-checking right before
+        // broadcast - rather than trusting the lease we captured at claim time - is what stops
+        // both nodes from sending with their own nonce managers at once.
+        if !db.relayer_lease_is_current(&self.relayer.id, &self.node_id, self.lease_epoch).await? {
+            return Err(TransactionQueueSendTransactionError::LeaseNoLongerHeld(self.relayer.id));
+        }
+
         let gas_price = self
             .compute_gas_price_for_transaction(
                 &transaction.speed,
@@ -1092,14 +1534,55 @@ impl TransactionsQueue {
         
         // If using safe proxy, the transaction value should be 0 because the ETH transfer
         // amount is encoded in the execTransaction call data, not in the transaction value
-        if self.safe_proxy_manager.is_some() && 
+        if self.safe_proxy_manager.is_some() &&
            self.safe_proxy_manager.as_ref().unwrap().get_safe_proxy_for_relayer(&self.relayer.address).is_some() {
             working_transaction.value = TransactionValue::zero();
         }
 
+        // An EIP-2930 relayer falls back to its default access list when the transaction didn't
+        // bring its own.
+        if self.relayer.preferred_envelope == TransactionEnvelopeType::EIP2930
+            && working_transaction.access_list.is_none()
+        {
+            working_transaction.access_list = self.relayer.default_access_list.clone();
+        }
+
         // First, estimate gas limit by creating a temporary transaction with a high gas limit
         let temp_gas_limit = GasLimit::new(10_000_000); // High temporary limit for estimation
 
+        // Relayers opted into `auto_access_list` get one generated via `eth_createAccessList`
+        // when the transaction didn't already bring or fall back to one. Legacy transactions
+        // can't carry an access list at all, so they're skipped.
+        if self.auto_access_list()
+            && working_transaction.access_list.is_none()
+            && !self.is_legacy_transactions()
+        {
+            if let Ok(probe_transaction) =
+                working_transaction.to_eip1559_typed_transaction(Some(&gas_price))
+            {
+                match self
+                    .evm_provider
+                    .generate_access_list(&probe_transaction, &self.relayer.address)
+                    .await
+                {
+                    Ok(Some(access_list)) => {
+                        info!(
+                            "Generated access list for transaction {} on relayer: {}",
+                            transaction.id, self.relayer.name
+                        );
+                        working_transaction.access_list = Some(access_list);
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        info!(
+                            "Could not generate access list for transaction {} on relayer: {}: {}",
+                            transaction.id, self.relayer.name, e
+                        );
+                    }
+                }
+            }
+        }
+
         let temp_transaction_request = if working_transaction.is_blob_transaction() {
             info!(
                 "Creating blob transaction for gas estimation for relayer: {}",
@@ -1130,6 +1613,16 @@ impl TransactionsQueue {
                 .map_err(|e| {
                     TransactionQueueSendTransactionError::TransactionConversionError(e.to_string())
                 })?
+        } else if self.relayer.preferred_envelope == TransactionEnvelopeType::EIP2930 {
+            info!(
+                "Creating EIP-2930 transaction for gas estimation for relayer: {}",
+                self.relayer.name
+            );
+            working_transaction
+                .to_eip2930_typed_transaction_with_gas_limit(Some(&gas_price), Some(temp_gas_limit))
+                .map_err(|e| {
+                    TransactionQueueSendTransactionError::TransactionConversionError(e.to_string())
+                })?
         } else {
             info!(
                 "Creating EIP-1559 transaction for gas estimation for relayer: {}",
@@ -1209,6 +1702,16 @@ impl TransactionsQueue {
                 .map_err(|e| {
                     TransactionQueueSendTransactionError::TransactionConversionError(e.to_string())
                 })?
+        } else if self.relayer.preferred_envelope == TransactionEnvelopeType::EIP2930 {
+            info!("Creating final EIP-2930 transaction for relayer: {}", self.relayer.name);
+            working_transaction
+                .to_eip2930_typed_transaction_with_gas_limit(
+                    Some(&gas_price),
+                    Some(estimated_gas_limit),
+                )
+                .map_err(|e| {
+                    TransactionQueueSendTransactionError::TransactionConversionError(e.to_string())
+                })?
         } else {
             info!("Creating final EIP-1559 transaction for relayer: {}", self.relayer.name);
             working_transaction
@@ -1255,9 +1758,11 @@ impl TransactionsQueue {
             );
             if transaction.sent_with_gas.is_none() {
                 db.transaction_sent(
+                    &self.relayer.id,
                     &transaction_sent.id,
                     &transaction_sent.hash,
                     &transaction_sent.sent_with_gas,
+                    None,
                     self.is_legacy_transactions(),
                 )
                 .await
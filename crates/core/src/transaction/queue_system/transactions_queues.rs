@@ -11,6 +11,18 @@ use chrono::{DateTime, Utc};
 use thiserror::Error;
 use tokio::sync::Mutex;
 use tracing::{error, info};
+use uuid::Uuid;
+
+/// Hard ceiling on pending + inmempool transactions across every relayer combined, independent of
+/// any single relayer's own `per_relayer_max_inflight`. Protects shared resources (DB connections,
+/// provider rate limits) from a fleet of otherwise-healthy relayers collectively overwhelming them.
+const GLOBAL_MAX_INFLIGHT: usize = 50_000;
+
+/// How long after its last broadcast (`sent_at`) a `FEECAPPED` transaction at the front of a
+/// relayer's inmempool queue - blocking every nonce behind it - is left before
+/// `rescue_stuck_transaction` steps in and resends it as a no-op at a fresh, non-escalated gas
+/// quote just to clear the nonce.
+const STUCK_TRANSACTION_RESCUE_TIMEOUT_MS: u64 = 15 * 60 * 1000;
 
 /// Error types for transaction queues operations.
 #[derive(Error, Debug)]
@@ -23,9 +35,10 @@ pub enum TransactionsQueuesError {
 
 use super::{
     start::spawn_processing_tasks_for_relayer,
+    tasks::RelayerTaskRegistry,
     transactions_queue::TransactionsQueue,
     types::{
-        AddTransactionError, CancelTransactionError, CancelTransactionResult,
+        AddTransactionError, CancelTransactionError, CancelTransactionResult, CanonicalCheck,
         CompetitionResolutionResult, CompetitionType, EditableTransactionType,
         ProcessInmempoolStatus, ProcessInmempoolTransactionError, ProcessMinedStatus,
         ProcessMinedTransactionError, ProcessPendingStatus, ProcessPendingTransactionError,
@@ -34,12 +47,12 @@ use super::{
     },
 };
 use crate::transaction::api::RelayTransactionRequest;
-use crate::transaction::queue_system::types::SendTransactionGasPriceError;
+use crate::transaction::queue_system::types::{SendTransactionGasPriceError, TransactionScore};
 use crate::transaction::types::{TransactionBlob, TransactionConversionError, TransactionSpeed};
 use crate::{
     gas::{BlobGasOracleCache, BlobGasPriceResult, GasLimit, GasOracleCache, GasPriceResult},
     postgres::{PostgresClient, PostgresConnectionError},
-    relayer::RelayerId,
+    relayer::{OnchainAllowlistCache, RelayerId},
     safe_proxy::SafeProxyManager,
     shared::{cache::Cache, common_types::WalletOrProviderError},
     transaction::{
@@ -48,6 +61,7 @@ use crate::{
         queue_system::types::TransactionQueueSendTransactionError,
         types::{Transaction, TransactionData, TransactionId, TransactionStatus, TransactionValue},
     },
+    WalletError,
     webhooks::WebhookManager,
 };
 
@@ -64,6 +78,8 @@ pub struct TransactionsQueues {
     cache: Arc<Cache>,
     webhook_manager: Option<Arc<Mutex<WebhookManager>>>,
     safe_proxy_manager: Arc<SafeProxyManager>,
+    onchain_allowlist_cache: Arc<OnchainAllowlistCache>,
+    relayer_task_registry: RelayerTaskRegistry,
 }
 
 impl TransactionsQueues {
@@ -94,6 +110,12 @@ impl TransactionsQueues {
                         setup.inmempool_transactions,
                         setup.mined_transactions,
                         safe_proxy_manager.clone(),
+                        setup.gas_bump_config,
+                        setup.max_gas_price_multiplier,
+                        setup.nonce_cap,
+                        setup.per_relayer_max_inflight,
+                        setup.node_id,
+                        setup.lease_epoch,
                     ),
                     gas_oracle_cache.clone(),
                     blob_gas_oracle_cache.clone(),
@@ -110,9 +132,17 @@ impl TransactionsQueues {
             cache,
             webhook_manager,
             safe_proxy_manager,
+            onchain_allowlist_cache: Arc::new(OnchainAllowlistCache::new()),
+            relayer_task_registry: RelayerTaskRegistry::new(),
         })
     }
 
+    /// Returns a handle to the registry of spawned per-relayer processing tasks, so the caller can
+    /// wire it into graceful shutdown.
+    pub fn relayer_task_registry(&self) -> RelayerTaskRegistry {
+        self.relayer_task_registry.clone()
+    }
+
     /// Retrieves a transaction queue for the specified relayer.
     pub fn get_transactions_queue(
         &self,
@@ -132,16 +162,37 @@ impl TransactionsQueues {
             .ok_or_else(|| format!("transactions queue does not exist for relayer: {}", relayer_id))
     }
 
-    /// Removes a transaction queue for the specified relayer.
+    /// Removes a transaction queue for the specified relayer, immediately tearing down its
+    /// pending, in-mempool, and mined processing tasks rather than waiting for them to notice the
+    /// queue is gone on their next poll.
     pub async fn delete_queue(&mut self, relayer_id: &RelayerId) {
+        self.relayer_task_registry.stop(relayer_id).await;
         self.queues.remove(relayer_id);
     }
 
+    /// Collects the notification wakeup handles for every currently known relayer, keyed by
+    /// relayer id. Used by both the processing loops (to wait on) and the relayer notification
+    /// listener (to fire into from an incoming Postgres NOTIFY).
+    pub async fn notify_handles(&self) -> HashMap<RelayerId, super::notify::RelayerQueueNotify> {
+        let mut handles = HashMap::new();
+        for (relayer_id, queue_arc) in &self.queues {
+            let queue = queue_arc.lock().await;
+            handles.insert(*relayer_id, queue.notify_handles());
+        }
+        handles
+    }
+
     /// Invalidates the cache entry for a specific transaction.
     async fn invalidate_transaction_cache(&self, id: &TransactionId) {
         invalidate_transaction_no_state_cache(&self.cache, id).await;
     }
 
+    /// Drops every cached `certified(address)` lookup, so a relayer whose allowlist contract
+    /// address just changed doesn't keep trusting certifications fetched from the old one.
+    pub async fn invalidate_onchain_allowlist_cache(&self) {
+        self.onchain_allowlist_cache.invalidate_all().await;
+    }
+
     /// Returns the count of pending transactions for a specific relayer.
     pub async fn pending_transactions_count(&self, relayer_id: &RelayerId) -> usize {
         if let Some(queue_arc) = self.get_transactions_queue(relayer_id) {
@@ -162,6 +213,45 @@ impl TransactionsQueues {
         }
     }
 
+    /// Returns the count of transactions ready to be broadcast (sent, awaiting mining) for a
+    /// specific relayer.
+    pub async fn ready_transactions_count(&self, relayer_id: &RelayerId) -> usize {
+        if let Some(queue_arc) = self.get_transactions_queue(relayer_id) {
+            let queue = queue_arc.lock().await;
+            queue.ready_transaction_count().await
+        } else {
+            0
+        }
+    }
+
+    /// Returns the count of transactions still waiting on a future nonce to be sent for a
+    /// specific relayer.
+    pub async fn future_transactions_count(&self, relayer_id: &RelayerId) -> usize {
+        if let Some(queue_arc) = self.get_transactions_queue(relayer_id) {
+            let queue = queue_arc.lock().await;
+            queue.future_transaction_count().await
+        } else {
+            0
+        }
+    }
+
+    /// Returns the combined pending + inmempool transaction count for a specific relayer.
+    pub async fn inflight_transactions_count(&self, relayer_id: &RelayerId) -> usize {
+        self.pending_transactions_count(relayer_id).await
+            + self.inmempool_transactions_count(relayer_id).await
+    }
+
+    /// Returns the combined pending + inmempool transaction count across every relayer.
+    async fn total_inflight_count(&self) -> usize {
+        let mut total = 0;
+        for queue_arc in self.queues.values() {
+            let queue = queue_arc.lock().await;
+            total += queue.get_pending_transaction_count().await;
+            total += queue.get_inmempool_transaction_count().await;
+        }
+        total
+    }
+
     /// Adds a new relayer and its transaction queue to the system.
     pub async fn add_new_relayer(
         &mut self,
@@ -182,13 +272,66 @@ impl TransactionsQueues {
                     VecDeque::new(),
                     HashMap::new(),
                     self.safe_proxy_manager.clone(),
+                    setup.gas_bump_config,
+                    setup.max_gas_price_multiplier,
+                    setup.nonce_cap,
+                    setup.per_relayer_max_inflight,
+                    setup.node_id,
+                    setup.lease_epoch,
+                ),
+                self.gas_oracle_cache.clone(),
+                self.blob_gas_oracle_cache.clone(),
+            ))),
+        );
+
+        spawn_processing_tasks_for_relayer(queues_arc, &relayer_id, &self.relayer_task_registry)
+            .await;
+
+        Ok(())
+    }
+
+    /// Adds a relayer this node just claimed ownership of during a work-claiming rebalance pass.
+    ///
+    /// Unlike `add_new_relayer` - which is for a relayer that has never run anywhere and so
+    /// always starts with empty queues - a claimed relayer may carry pending, in-mempool, or
+    /// mined transactions left behind by the node that previously owned it, so its queues are
+    /// built from the `TransactionRelayerSetup` the caller repopulated from the database rather
+    /// than starting empty.
+    pub async fn add_claimed_relayer(
+        &mut self,
+        setup: TransactionRelayerSetup,
+        queues_arc: Arc<Mutex<TransactionsQueues>>,
+    ) -> Result<(), WalletOrProviderError> {
+        let current_nonce = setup.evm_provider.get_nonce(&setup.relayer.wallet_index).await?;
+        let relayer_id = setup.relayer.id;
+
+        self.relayer_block_times_ms.insert(relayer_id, setup.evm_provider.blocks_every);
+
+        self.queues.insert(
+            relayer_id,
+            Arc::new(Mutex::new(TransactionsQueue::new(
+                TransactionsQueueSetup::new(
+                    setup.relayer,
+                    setup.evm_provider,
+                    NonceManager::new(current_nonce),
+                    setup.pending_transactions,
+                    setup.inmempool_transactions,
+                    setup.mined_transactions,
+                    self.safe_proxy_manager.clone(),
+                    setup.gas_bump_config,
+                    setup.max_gas_price_multiplier,
+                    setup.nonce_cap,
+                    setup.per_relayer_max_inflight,
+                    setup.node_id,
+                    setup.lease_epoch,
                 ),
                 self.gas_oracle_cache.clone(),
                 self.blob_gas_oracle_cache.clone(),
             ))),
         );
 
-        spawn_processing_tasks_for_relayer(queues_arc, &relayer_id).await;
+        spawn_processing_tasks_for_relayer(queues_arc, &relayer_id, &self.relayer_task_registry)
+            .await;
 
         Ok(())
     }
@@ -242,6 +385,21 @@ impl TransactionsQueues {
         current_transaction.external_id = replace_with.external_id.clone();
     }
 
+    /// Wraps a failure from `add_transactions_batch` so it carries the ids of any earlier batch
+    /// members that were already applied (saved, queued, and webhook-notified) before this one
+    /// failed. Passes `err` through unchanged when nothing has been applied yet, so a failure on
+    /// the very first member still reads as a plain, non-batch-specific error.
+    fn wrap_batch_failure(queued: &[Transaction], err: AddTransactionError) -> AddTransactionError {
+        if queued.is_empty() {
+            return err;
+        }
+
+        AddTransactionError::BatchPartiallyApplied {
+            queued: queued.iter().map(|transaction| transaction.id).collect(),
+            source: Box::new(err),
+        }
+    }
+
     /// Computes gas prices for a transaction based on its type.
     async fn compute_transaction_gas_prices(
         transactions_queue: &TransactionsQueue,
@@ -254,7 +412,10 @@ impl TransactionsQueues {
             None
         };
 
-        let gas_price = transactions_queue.compute_gas_price_for_transaction(speed, None).await?;
+        let mut gas_price = transactions_queue.compute_gas_price_for_transaction(speed, None).await?;
+        gas_price.l1_data_fee = transactions_queue
+            .compute_l1_data_fee_for_transaction(transaction.data.as_bytes())
+            .await?;
 
         Ok((gas_price, blob_gas_price))
     }
@@ -319,7 +480,7 @@ impl TransactionsQueues {
             AddTransactionError::TransactionEstimateGasError(transaction.relayer_id, e)
         })?;
 
-        let gas_cost = estimated_gas_limit.into_inner() * gas_price.legacy_gas_price().into_u128();
+        let gas_cost = gas_price.total_fee_with_l1_data_fee(estimated_gas_limit.into_inner());
         let total_required =
             transaction.value.into_inner() + alloy::primitives::U256::from(gas_cost);
 
@@ -345,6 +506,10 @@ impl TransactionsQueues {
         relayer_id: &RelayerId,
         transaction_to_send: &TransactionToSend,
     ) -> Result<Transaction, AddTransactionError> {
+        if self.total_inflight_count().await >= GLOBAL_MAX_INFLIGHT {
+            return Err(AddTransactionError::GlobalInflightCapReached);
+        }
+
         let expires_at = self.expires_at();
 
         let queue_arc = self
@@ -357,6 +522,17 @@ impl TransactionsQueues {
             return Err(AddTransactionError::RelayerIsPaused(*relayer_id));
         }
 
+        if !transactions_queue
+            .is_recipient_certified(&self.onchain_allowlist_cache, &transaction_to_send.to)
+            .await
+            .map_err(AddTransactionError::OnchainAllowlistError)?
+        {
+            return Err(AddTransactionError::RecipientNotCertified(
+                *relayer_id,
+                transaction_to_send.to,
+            ));
+        }
+
         // Check if this is a blob transaction and if the wallet manager supports blobs
         if transaction_to_send.blobs.is_some() && !transactions_queue.supports_blobs() {
             return Err(AddTransactionError::UnsupportedTransactionType {
@@ -365,6 +541,27 @@ impl TransactionsQueues {
             });
         }
 
+        // Checked before the nonce is assigned - `NonceManager` has no way to give a nonce back,
+        // so an admission rejection after this point would burn it permanently.
+        if let Some(evicted) = transactions_queue
+            .try_admit_pending_transaction(
+                &transaction_to_send.id,
+                TransactionScore::of_speed(&transaction_to_send.speed),
+            )
+            .await?
+        {
+            self.db
+                .transaction_failed_on_send(
+                    relayer_id,
+                    &evicted,
+                    "Evicted from the pending queue to admit a higher-priority transaction",
+                )
+                .await
+                .map_err(AddTransactionError::CouldNotSaveTransactionDb)?;
+
+            self.invalidate_transaction_cache(&evicted.id).await;
+        }
+
         let assigned_nonce = transactions_queue.nonce_manager.get_and_increment().await;
 
         let mut transaction = Transaction {
@@ -385,6 +582,7 @@ impl TransactionsQueues {
             sent_at: None,
             mined_at: None,
             mined_at_block_number: None,
+            mined_at_block_hash: None,
             confirmed_at: None,
             speed: transaction_to_send.speed.clone(),
             sent_with_max_priority_fee_per_gas: None,
@@ -462,6 +660,207 @@ impl TransactionsQueues {
         Ok(transaction)
     }
 
+    /// Adds an ordered batch of transactions to a single relayer's queue. Only the up-front
+    /// checks (pause state, recipient allowlist, blob support) are all-or-nothing; past that
+    /// point each member is admitted, nonce-assigned, priced, estimated, and persisted one at a
+    /// time while holding the relayer's queue lock, so no other caller can interleave a
+    /// transaction into this relayer's queue while the batch is being assigned, but a downstream
+    /// failure (e.g. gas estimation) on one member does not roll back the members that already
+    /// succeeded - they remain saved, queued, and webhook-notified. In that case the call still
+    /// returns `Err`, but as `AddTransactionError::BatchPartiallyApplied` carrying the ids of
+    /// those already-applied members, so the caller can tell the batch was not atomic instead of
+    /// assuming it's safe to retry as a whole. Emits the usual per-transaction lifecycle webhook
+    /// for each queued member plus a single `batch_queued` webhook for the whole batch.
+    pub async fn add_transactions_batch(
+        &mut self,
+        relayer_id: &RelayerId,
+        batch_id: Uuid,
+        batch: &[TransactionToSend],
+    ) -> Result<Vec<Transaction>, AddTransactionError> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if self.total_inflight_count().await + batch.len() > GLOBAL_MAX_INFLIGHT {
+            return Err(AddTransactionError::GlobalInflightCapReached);
+        }
+
+        let expires_at = self.expires_at();
+
+        let queue_arc = self
+            .get_transactions_queue(relayer_id)
+            .ok_or(AddTransactionError::RelayerNotFound(*relayer_id))?;
+
+        let mut transactions_queue = queue_arc.lock().await;
+
+        if transactions_queue.is_paused() {
+            return Err(AddTransactionError::RelayerIsPaused(*relayer_id));
+        }
+
+        for transaction_to_send in batch {
+            if !transactions_queue
+                .is_recipient_certified(&self.onchain_allowlist_cache, &transaction_to_send.to)
+                .await
+                .map_err(AddTransactionError::OnchainAllowlistError)?
+            {
+                return Err(AddTransactionError::RecipientNotCertified(
+                    *relayer_id,
+                    transaction_to_send.to,
+                ));
+            }
+
+            if transaction_to_send.blobs.is_some() && !transactions_queue.supports_blobs() {
+                return Err(AddTransactionError::UnsupportedTransactionType {
+                    message: "EIP-4844 blob transactions are not supported by this wallet manager"
+                        .to_string(),
+                });
+            }
+        }
+
+        let mut queued = Vec::with_capacity(batch.len());
+
+        for transaction_to_send in batch {
+            // Checked before the nonce is assigned - `NonceManager` has no way to give a nonce
+            // back, so an admission rejection after this point would burn it permanently.
+            let evicted = transactions_queue
+                .try_admit_pending_transaction(
+                    &transaction_to_send.id,
+                    TransactionScore::of_speed(&transaction_to_send.speed),
+                )
+                .await
+                .map_err(|err| Self::wrap_batch_failure(&queued, err.into()))?;
+
+            if let Some(evicted) = evicted {
+                self.db
+                    .transaction_failed_on_send(
+                        relayer_id,
+                        &evicted,
+                        "Evicted from the pending queue to admit a higher-priority transaction",
+                    )
+                    .await
+                    .map_err(AddTransactionError::CouldNotSaveTransactionDb)
+                    .map_err(|err| Self::wrap_batch_failure(&queued, err))?;
+
+                self.invalidate_transaction_cache(&evicted.id).await;
+            }
+
+            let assigned_nonce = transactions_queue.nonce_manager.get_and_increment().await;
+
+            let mut transaction = Transaction {
+                id: transaction_to_send.id,
+                relayer_id: *relayer_id,
+                to: transaction_to_send.to,
+                from: transactions_queue.relay_address(),
+                value: transaction_to_send.value,
+                data: transaction_to_send.data.clone(),
+                nonce: assigned_nonce,
+                gas_limit: None,
+                status: TransactionStatus::PENDING,
+                blobs: transaction_to_send.blobs.clone(),
+                chain_id: transactions_queue.chain_id(),
+                known_transaction_hash: None,
+                queued_at: Utc::now(),
+                expires_at,
+                sent_at: None,
+                mined_at: None,
+                mined_at_block_number: None,
+                mined_at_block_hash: None,
+                confirmed_at: None,
+                speed: transaction_to_send.speed.clone(),
+                sent_with_max_priority_fee_per_gas: None,
+                sent_with_max_fee_per_gas: None,
+                is_noop: false,
+                sent_with_gas: None,
+                sent_with_blob_gas: None,
+                external_id: transaction_to_send.external_id.clone(),
+                cancelled_by_transaction_id: None,
+            };
+
+            let (gas_price, blob_gas_price) = Self::compute_transaction_gas_prices(
+                &transactions_queue,
+                &transaction,
+                &transaction_to_send.speed,
+            )
+            .await
+            .map_err(|err| Self::wrap_batch_failure(&queued, err.into()))?;
+
+            let estimated_gas_limit = match Self::estimate_and_validate_gas(
+                &mut transactions_queue,
+                &transaction,
+                &gas_price,
+                blob_gas_price.as_ref(),
+            )
+            .await
+            {
+                Ok(limit) => limit,
+                Err(err) => {
+                    self.db
+                        .transaction_failed_on_send(
+                            relayer_id,
+                            &transaction,
+                            "Failed to send transaction as always failing on gas estimation",
+                        )
+                        .await
+                        .map_err(AddTransactionError::CouldNotSaveTransactionDb)
+                        .map_err(|db_err| Self::wrap_batch_failure(&queued, db_err))?;
+
+                    self.invalidate_transaction_cache(&transaction.id).await;
+                    return Err(Self::wrap_batch_failure(&queued, err));
+                }
+            };
+
+            transaction.gas_limit = Some(estimated_gas_limit);
+
+            let transaction_request = Self::create_typed_transaction(
+                &transactions_queue,
+                &transaction,
+                &gas_price,
+                blob_gas_price.as_ref(),
+                estimated_gas_limit,
+            )
+            .map_err(|err| Self::wrap_batch_failure(&queued, err.into()))?;
+
+            transaction.known_transaction_hash = Some(
+                transactions_queue.compute_tx_hash(&transaction_request).await.map_err(|err| {
+                    Self::wrap_batch_failure(&queued, AddTransactionError::from(WalletError::from(err)))
+                })?,
+            );
+
+            self.db
+                .save_transaction(relayer_id, &transaction)
+                .await
+                .map_err(AddTransactionError::CouldNotSaveTransactionDb)
+                .map_err(|err| Self::wrap_batch_failure(&queued, err))?;
+
+            transactions_queue.add_pending_transaction(transaction.clone()).await;
+            self.invalidate_transaction_cache(&transaction.id).await;
+
+            if let Some(webhook_manager) = &self.webhook_manager {
+                let webhook_manager = webhook_manager.clone();
+                let transaction_clone = transaction.clone();
+                tokio::spawn(async move {
+                    let webhook_manager = webhook_manager.lock().await;
+                    webhook_manager.on_transaction_queued(&transaction_clone).await;
+                });
+            }
+
+            queued.push(transaction);
+        }
+
+        if let Some(webhook_manager) = &self.webhook_manager {
+            let webhook_manager = webhook_manager.clone();
+            let relayer_id = *relayer_id;
+            let chain_id = transactions_queue.chain_id();
+            let queued_clone = queued.clone();
+            tokio::spawn(async move {
+                let webhook_manager = webhook_manager.lock().await;
+                webhook_manager.on_batch_queued(relayer_id, chain_id, batch_id, &queued_clone).await;
+            });
+        }
+
+        Ok(queued)
+    }
+
     /// Cancels an existing transaction.
     ///
     /// For PENDING transactions: Simply removes from queue and marks as CANCELLED.
@@ -562,6 +961,7 @@ impl TransactionsQueues {
                             sent_at: None,
                             mined_at: None,
                             mined_at_block_number: None,
+                            mined_at_block_hash: None,
                             confirmed_at: None,
                             speed: TransactionSpeed::SUPER, // Use highest speed for faster replacement
                             sent_with_max_priority_fee_per_gas: None,
@@ -594,6 +994,7 @@ impl TransactionsQueues {
                             max_priority_fee: bumped_max_priority_fee,
                             min_wait_time_estimate: None,
                             max_wait_time_estimate: None,
+                            l1_data_fee: None,
                         };
 
                         // Blob gas price is not needed for cancel transactions (they're simple transfers)
@@ -798,6 +1199,7 @@ impl TransactionsQueues {
                             sent_at: None,
                             mined_at: None,
                             mined_at_block_number: None,
+                            mined_at_block_hash: None,
                             confirmed_at: None,
                             speed: TransactionSpeed::SUPER, // Use highest speed for faster replacement
                             sent_with_max_priority_fee_per_gas: None,
@@ -844,6 +1246,7 @@ impl TransactionsQueues {
                             max_priority_fee: bumped_max_priority_fee,
                             min_wait_time_estimate: None,
                             max_wait_time_estimate: None,
+                            l1_data_fee: None,
                         };
 
                         // Handle blob gas pricing for replace transactions if needed
@@ -1092,6 +1495,15 @@ impl TransactionsQueues {
                                     TransactionQueueSendTransactionError::NoTransactionInQueue,
                                 ))
                             }
+                            TransactionQueueSendTransactionError::LeaseNoLongerHeld(relayer_id) => {
+                                // Another node has reclaimed this relayer - stop processing it here
+                                // rather than race that node's nonce manager.
+                                Err(ProcessPendingTransactionError::SendTransactionError(
+                                    TransactionQueueSendTransactionError::LeaseNoLongerHeld(
+                                        relayer_id,
+                                    ),
+                                ))
+                            }
                         };
                     }
                 }
@@ -1108,6 +1520,104 @@ impl TransactionsQueues {
         }
     }
 
+    /// Checks the front of a relayer's inmempool queue for a transaction that is `FEECAPPED` -
+    /// the escalator already gave up on it - and was last broadcast (`sent_at`) longer than
+    /// [`STUCK_TRANSACTION_RESCUE_TIMEOUT_MS`] ago, and if so rescues it: converts it to a no-op
+    /// self-send and resends it at a fresh market-rate gas quote (not bound by the old
+    /// escalation chain's capped fee), so the nonce it holds finally clears and every
+    /// transaction queued behind it can drain. Returns `Ok(None)` when there is nothing to
+    /// rescue yet - no inmempool transaction, or one that isn't stuck, or one whose timeout
+    /// hasn't elapsed.
+    pub async fn rescue_stuck_transaction(
+        &mut self,
+        relayer_id: &RelayerId,
+    ) -> Result<Option<Transaction>, ProcessInmempoolTransactionError> {
+        let queue_arc = self
+            .get_transactions_queue(relayer_id)
+            .ok_or(ProcessInmempoolTransactionError::RelayerTransactionsQueueNotFound(
+                *relayer_id,
+            ))?;
+
+        let mut transactions_queue = queue_arc.lock().await;
+
+        let Some(stuck_transaction) = transactions_queue.get_next_inmempool_transaction().await
+        else {
+            return Ok(None);
+        };
+
+        if stuck_transaction.status != TransactionStatus::FEECAPPED {
+            return Ok(None);
+        }
+
+        let Some(sent_at) = stuck_transaction.sent_at else {
+            return Ok(None);
+        };
+
+        let elapsed_ms = (Utc::now() - sent_at).num_milliseconds().max(0) as u64;
+        if elapsed_ms < STUCK_TRANSACTION_RESCUE_TIMEOUT_MS {
+            return Ok(None);
+        }
+
+        info!(
+            "Rescuing transaction {} stuck FEECAPPED at nonce {:?} for relayer {}",
+            stuck_transaction.id, stuck_transaction.nonce, relayer_id
+        );
+
+        let mut rescue_transaction = stuck_transaction.clone();
+        self.transaction_to_noop(&mut transactions_queue, &mut rescue_transaction);
+        // Reset every gas field so the escalator's old, capped quote doesn't carry over - this
+        // rescue gets a clean market-rate quote instead of inheriting the ceiling that got it
+        // stuck in the first place.
+        rescue_transaction.sent_with_gas = None;
+        rescue_transaction.sent_with_blob_gas = None;
+        rescue_transaction.sent_with_max_fee_per_gas = None;
+        rescue_transaction.sent_with_max_priority_fee_per_gas = None;
+        rescue_transaction.status = TransactionStatus::INMEMPOOL;
+        rescue_transaction.known_transaction_hash = None;
+
+        let transaction_sent = transactions_queue
+            .send_transaction(&mut self.db, &mut rescue_transaction)
+            .await
+            .map_err(ProcessInmempoolTransactionError::SendTransactionError)?;
+
+        rescue_transaction.known_transaction_hash = Some(transaction_sent.hash);
+        rescue_transaction.sent_with_max_fee_per_gas = Some(transaction_sent.sent_with_gas.max_fee);
+        rescue_transaction.sent_with_max_priority_fee_per_gas =
+            Some(transaction_sent.sent_with_gas.max_priority_fee);
+        rescue_transaction.sent_with_gas = Some(transaction_sent.sent_with_gas.clone());
+        rescue_transaction.sent_at = Some(Utc::now());
+        rescue_transaction.resubmission_count += 1;
+
+        transactions_queue
+            .update_inmempool_transaction_noop(&stuck_transaction.id, &transaction_sent)
+            .await;
+        transactions_queue
+            .update_inmempool_transaction_status(&stuck_transaction.id, TransactionStatus::INMEMPOOL)
+            .await;
+
+        self.db.transaction_update(&rescue_transaction).await.map_err(|e| {
+            ProcessInmempoolTransactionError::CouldNotUpdateTransactionStatusInTheDatabase(
+                *relayer_id,
+                rescue_transaction.clone(),
+                TransactionStatus::INMEMPOOL,
+                e,
+            )
+        })?;
+        self.invalidate_transaction_cache(&rescue_transaction.id).await;
+
+        if let Some(webhook_manager) = &self.webhook_manager {
+            let webhook_manager = webhook_manager.clone();
+            let rescued = rescue_transaction.clone();
+            let original = stuck_transaction.clone();
+            tokio::spawn(async move {
+                let webhook_manager = webhook_manager.lock().await;
+                webhook_manager.on_transaction_rescued(&rescued, &original).await;
+            });
+        }
+
+        Ok(Some(rescue_transaction))
+    }
+
     /// Processes a single in-mempool transaction for the specified relayer.
     pub async fn process_single_inmempool(
         &mut self,
@@ -1211,6 +1721,56 @@ impl TransactionsQueues {
                                     elapsed.num_milliseconds() as u64,
                                     &transaction.speed,
                                 ) {
+                                    // Work out what the escalator would send next before
+                                    // actually sending it, so a transaction that has hit its
+                                    // own resubmission ceiling stops here instead of looping
+                                    // forever on `GasPriceTooHigh` every poll.
+                                    let next_gas_price = transactions_queue
+                                        .compute_gas_price_for_transaction(
+                                            &transaction.speed,
+                                            transaction.sent_with_gas.as_ref(),
+                                        )
+                                        .await
+                                        .ok();
+
+                                    let can_escalate = match &next_gas_price {
+                                        Some(gas_price) => {
+                                            transactions_queue.can_escalate(&transaction, gas_price)
+                                        }
+                                        None => false,
+                                    };
+
+                                    if !can_escalate {
+                                        self.db
+                                            .update_transaction_fee_capped(&transaction.id)
+                                            .await
+                                            .map_err(|e| ProcessInmempoolTransactionError::CouldNotUpdateTransactionStatusInTheDatabase(*relayer_id, transaction.clone(), TransactionStatus::FEECAPPED, e))?;
+                                        transactions_queue
+                                            .update_inmempool_transaction_status(
+                                                &transaction.id,
+                                                TransactionStatus::FEECAPPED,
+                                            )
+                                            .await;
+                                        self.invalidate_transaction_cache(&transaction.id).await;
+
+                                        if let Some(webhook_manager) = &self.webhook_manager {
+                                            let webhook_manager = webhook_manager.clone();
+                                            let mut stuck_transaction = transaction.clone();
+                                            stuck_transaction.status = TransactionStatus::FEECAPPED;
+                                            tokio::spawn(async move {
+                                                let webhook_manager = webhook_manager.lock().await;
+                                                webhook_manager
+                                                    .on_transaction_stuck(&stuck_transaction)
+                                                    .await;
+                                            });
+                                        }
+
+                                        return Ok(ProcessResult::<ProcessInmempoolStatus>::other(
+                                            ProcessInmempoolStatus::EscalationLimitReached,
+                                            Default::default(),
+                                        ));
+                                    }
+
                                     let transaction_sent = transactions_queue
                                         .send_transaction(&mut self.db, &mut transaction)
                                         .await?;
@@ -1230,6 +1790,7 @@ impl TransactionsQueues {
                                     transaction.sent_with_gas =
                                         Some(transaction_sent.sent_with_gas.clone());
                                     transaction.sent_at = Some(Utc::now());
+                                    transaction.resubmission_count += 1;
 
                                     self.invalidate_transaction_cache(&transaction.id).await;
 
@@ -1280,6 +1841,55 @@ impl TransactionsQueues {
 
             if let Some(transaction) = transactions_queue.get_next_mined_transaction().await {
                 if let Some(mined_at) = transaction.mined_at {
+                    if let (Some(block_number), Some(block_hash)) =
+                        (transaction.mined_at_block_number, transaction.mined_at_block_hash)
+                    {
+                        let check = transactions_queue
+                            .check_mined_block_confirmation(block_number.into(), block_hash.into())
+                            .await
+                            .map_err(|e| {
+                                ProcessMinedTransactionError::CouldNotCheckBlockConfirmation(
+                                    *relayer_id,
+                                    transaction.clone(),
+                                    e,
+                                )
+                            })?;
+
+                        if check == CanonicalCheck::Reorged {
+                            self.db.transaction_reorged(&transaction).await.map_err(|e| {
+                                ProcessMinedTransactionError::TransactionReorgedNotSavedToDatabase(
+                                    *relayer_id,
+                                    transaction.clone(),
+                                    e,
+                                )
+                            })?;
+
+                            let rolled_back = transactions_queue
+                                .move_mined_to_reorged(&transaction.id)
+                                .await;
+
+                            self.invalidate_transaction_cache(&transaction.id).await;
+
+                            if let (Some(webhook_manager), Some(rolled_back)) =
+                                (&self.webhook_manager, rolled_back)
+                            {
+                                let webhook_manager = webhook_manager.clone();
+                                let mined_transaction = transaction.clone();
+                                tokio::spawn(async move {
+                                    let webhook_manager = webhook_manager.lock().await;
+                                    webhook_manager
+                                        .on_transaction_reorged(&rolled_back, &mined_transaction)
+                                        .await;
+                                });
+                            }
+
+                            return Ok(ProcessResult::<ProcessMinedStatus>::other(
+                                ProcessMinedStatus::Reorged,
+                                Default::default(),
+                            ));
+                        }
+                    }
+
                     let elapsed = Utc::now() - mined_at;
                     if transactions_queue.in_confirmed_range(elapsed.num_milliseconds() as u64) {
                         let receipt = if let Some(tx_hash) = transaction.known_transaction_hash {
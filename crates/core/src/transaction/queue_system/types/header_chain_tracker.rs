@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+
+use alloy::primitives::B256;
+
+/// The minimal slice of a block header needed to walk the canonical chain - its own identity and
+/// a link to its parent.
+#[derive(Debug, Clone, Copy)]
+pub struct TrackedBlockHeader {
+    pub number: u64,
+    pub hash: B256,
+    pub parent_hash: B256,
+}
+
+/// Outcome of checking whether a previously-mined block is still part of the canonical chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanonicalCheck {
+    /// The block is still canonical. `confirmations` is how many blocks have built on top of it.
+    StillCanonical { confirmations: u64 },
+    /// A different block now sits at that height - the chain reorged the tracked block away.
+    Reorged,
+}
+
+/// Keeps a rolling window of the most recently observed block headers for a single network
+/// (hash, parent hash, number), so confirmation tracking can tell whether a mined transaction's
+/// block is still part of the canonical chain by counting canonical ancestors between it and the
+/// current head, rather than assuming the chain only ever grows linearly by block count.
+pub struct HeaderChainTracker {
+    /// Headers keyed by block number. Bounded to `capacity` entries, oldest evicted first.
+    headers: BTreeMap<u64, TrackedBlockHeader>,
+    capacity: usize,
+}
+
+impl HeaderChainTracker {
+    pub fn new(capacity: usize) -> Self {
+        Self { headers: BTreeMap::new(), capacity }
+    }
+
+    /// Records an observed header, replacing any header previously tracked at the same height -
+    /// a reorg may put a different block at a height already seen - then evicts the oldest
+    /// entries once the window exceeds `capacity`.
+    pub fn record(&mut self, header: TrackedBlockHeader) {
+        self.headers.insert(header.number, header);
+
+        while self.headers.len() > self.capacity {
+            let Some(&oldest) = self.headers.keys().next() else {
+                break;
+            };
+            self.headers.remove(&oldest);
+        }
+    }
+
+    /// Returns the canonical hash tracked at `number`, if it's within the rolling window.
+    pub fn canonical_hash_at(&self, number: u64) -> Option<B256> {
+        self.headers.get(&number).map(|header| header.hash)
+    }
+
+    /// Checks whether `hash` is still the canonical block at `number`, given a freshly observed
+    /// chain head at `head_number`. Returns `None` when `number` isn't covered by the rolling
+    /// window, meaning the caller needs to fetch that height directly before a verdict can be
+    /// reached.
+    pub fn check(&self, number: u64, hash: B256, head_number: u64) -> Option<CanonicalCheck> {
+        let canonical_hash = self.canonical_hash_at(number)?;
+
+        if canonical_hash == hash {
+            Some(CanonicalCheck::StillCanonical { confirmations: head_number.saturating_sub(number) })
+        } else {
+            Some(CanonicalCheck::Reorged)
+        }
+    }
+}
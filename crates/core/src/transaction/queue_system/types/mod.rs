@@ -23,9 +23,22 @@ pub use transactions_queue_setup::TransactionsQueueSetup;
 mod transaction_sent_with_relayer;
 pub use transaction_sent_with_relayer::TransactionSentWithRelayer;
 
+mod transaction_score;
+pub use transaction_score::TransactionScore;
+
+mod nonce_cap;
+pub use nonce_cap::NonceCap;
+
+mod node_id;
+pub use node_id::NodeId;
+
+mod header_chain_tracker;
+pub use header_chain_tracker::{CanonicalCheck, HeaderChainTracker, TrackedBlockHeader};
+
 mod transactions_queues_custom_errors;
 pub use transactions_queues_custom_errors::{
     AddTransactionError, CancelTransactionError, MoveInmempoolTransactionToMinedError,
-    MovePendingTransactionToInmempoolError, ProcessInmempoolTransactionError,
-    ProcessMinedTransactionError, ProcessPendingTransactionError, ReplaceTransactionError,
+    MovePendingTransactionToInmempoolError, PendingQueueFullError,
+    ProcessInmempoolTransactionError, ProcessMinedTransactionError,
+    ProcessPendingTransactionError, ReplaceTransactionError,
 };
@@ -0,0 +1,56 @@
+use std::{error::Error, fmt::Display};
+
+use bytes::BytesMut;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use uuid::Uuid;
+
+/// Identifies a single running rrelayer process for the purposes of multi-instance work
+/// claiming. Generated fresh every time a process starts - nodes aren't expected to keep their
+/// identity across a restart, since a crash just lets the lease on whatever it had claimed expire
+/// and the next claim pass (its own restart, or another node) pick the work back up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeId(Uuid);
+
+impl NodeId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for NodeId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for NodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> FromSql<'a> for NodeId {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Uuid::from_sql(ty, raw).map(NodeId)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for NodeId {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send + 'static>> {
+        self.0.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as FromSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
@@ -0,0 +1,31 @@
+/// Bounds how many not-yet-sent transactions (i.e. future nonces) a single relayer may hold in
+/// its pending queue at once, so one relayer can't reserve an unbounded run of nonces ahead of
+/// what it has actually broadcast.
+///
+/// This is a hard ceiling independent of `TransactionScore` - unlike the per-relayer in-flight
+/// cap, reaching it always rejects the incoming transaction rather than evicting a weaker one,
+/// since letting a single caller keep displacing its own older transactions would still let it
+/// monopolise every future nonce.
+#[derive(Debug, Clone, Copy)]
+pub struct NonceCap(usize);
+
+impl NonceCap {
+    pub fn new(max_future_nonces: usize) -> Self {
+        Self(max_future_nonces)
+    }
+
+    pub fn max_future_nonces(&self) -> usize {
+        self.0
+    }
+
+    pub fn allows(&self, current_pending_count: usize) -> bool {
+        current_pending_count < self.0
+    }
+}
+
+impl Default for NonceCap {
+    /// Matches the historical unbounded behaviour for relayers that don't configure a cap.
+    fn default() -> Self {
+        Self(usize::MAX)
+    }
+}
@@ -1,7 +1,10 @@
 use alloy::transports::{RpcError, TransportErrorKind};
 use thiserror::Error;
 
-use crate::{postgres::PostgresError, provider::SendTransactionError, SafeProxyError};
+use crate::{
+    gas::L1DataFeeError, postgres::PostgresError, provider::SendTransactionError,
+    relayer::types::RelayerId, SafeProxyError,
+};
 
 #[derive(Error, Debug)]
 pub enum SendTransactionGasPriceError {
@@ -13,6 +16,9 @@ pub enum SendTransactionGasPriceError {
 
     #[error("Transaction has no last sent gas price object")]
     NoLastSentGas,
+
+    #[error("L1 data fee calculation error: {0}")]
+    L1DataFeeError(#[from] L1DataFeeError),
 }
 
 #[derive(Error, Debug)]
@@ -43,4 +49,7 @@ pub enum TransactionQueueSendTransactionError {
 
     #[error("No transaction found in queue")]
     NoTransactionInQueue,
+
+    #[error("Lease for relayer {0} is no longer held by this node - it has been reclaimed by another node")]
+    LeaseNoLongerHeld(RelayerId),
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use super::CompetitiveTransaction;
+use super::{CompetitiveTransaction, NodeId, NonceCap};
 use crate::{
     provider::EvmProvider,
     relayer::Relayer,
@@ -16,9 +16,16 @@ pub struct TransactionRelayerSetup {
     pub mined_transactions: HashMap<TransactionId, Transaction>,
     pub gas_bump_config: GasBumpBlockConfig,
     pub max_gas_price_multiplier: u64,
+    pub nonce_cap: NonceCap,
+    pub per_relayer_max_inflight: usize,
+    /// This node's identity and the fencing token it was handed when it claimed `relayer`, so the
+    /// send path can detect the lease being reclaimed by another node and abort cleanly.
+    pub node_id: NodeId,
+    pub lease_epoch: i64,
 }
 
 impl TransactionRelayerSetup {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         relayer: Relayer,
         evm_provider: EvmProvider,
@@ -27,6 +34,10 @@ impl TransactionRelayerSetup {
         mined_transactions: HashMap<TransactionId, Transaction>,
         gas_bump_config: GasBumpBlockConfig,
         max_gas_price_multiplier: u64,
+        nonce_cap: NonceCap,
+        per_relayer_max_inflight: usize,
+        node_id: NodeId,
+        lease_epoch: i64,
     ) -> Self {
         TransactionRelayerSetup {
             relayer,
@@ -36,6 +47,10 @@ impl TransactionRelayerSetup {
             mined_transactions,
             gas_bump_config,
             max_gas_price_multiplier,
+            nonce_cap,
+            per_relayer_max_inflight,
+            node_id,
+            lease_epoch,
         }
     }
 }
@@ -0,0 +1,42 @@
+use crate::transaction::types::{Transaction, TransactionSpeed};
+
+/// Relative priority score for a transaction sitting in a relayer's pending queue, used to decide
+/// which transaction to evict when the queue is at capacity.
+///
+/// Pending transactions don't carry `sent_with_gas` until they're actually broadcast, so most
+/// scores fall back to the requested speed tier; a transaction that has already been priced once
+/// (e.g. re-queued after a failed send) is scored instead on the sum of the max fee and max
+/// priority fee it was priced at, since that's a stronger signal of urgency than the tier it was
+/// originally requested at.
+///
+/// The two cases are kept in separate comparison classes rather than folded into one scalar, so a
+/// priced transaction always outranks every unpriced one regardless of the unpriced candidate's
+/// tier or the priced transaction's actual gas price - comparing a wei-denominated gas price
+/// directly against a tier would otherwise let the two scales collide in either direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TransactionScore(bool, u128);
+
+impl TransactionScore {
+    pub fn of(transaction: &Transaction) -> Self {
+        if let Some(gas) = &transaction.sent_with_gas {
+            return Self(true, gas.max_fee.into_u128() + gas.max_priority_fee.into_u128());
+        }
+
+        Self::of_speed(&transaction.speed)
+    }
+
+    /// Scores a not-yet-constructed candidate from its requested speed alone. Equivalent to
+    /// `of` for any transaction that hasn't been priced yet (i.e. `sent_with_gas` is `None`),
+    /// which every newly-admitted pending transaction is - letting the admission check run
+    /// before a nonce (and the rest of the `Transaction` struct) is assigned to it.
+    pub fn of_speed(speed: &TransactionSpeed) -> Self {
+        let tier = match speed {
+            TransactionSpeed::SLOW => 0,
+            TransactionSpeed::MEDIUM => 1,
+            TransactionSpeed::FAST => 2,
+            TransactionSpeed::SUPER => 3,
+        };
+
+        Self(false, tier)
+    }
+}
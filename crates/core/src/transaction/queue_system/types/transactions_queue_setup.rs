@@ -1,4 +1,4 @@
-use super::CompetitiveTransaction;
+use super::{CompetitiveTransaction, NodeId, NonceCap};
 use crate::{
     provider::EvmProvider,
     relayer::Relayer,
@@ -22,6 +22,16 @@ pub struct TransactionsQueueSetup {
     pub safe_proxy_manager: Arc<SafeProxyManager>,
     pub gas_bump_config: GasBumpBlockConfig,
     pub max_gas_price_multiplier: u64,
+    /// Bounds how many not-yet-sent transactions this relayer may queue ahead of what it has
+    /// broadcast. Defaults to unbounded for relayers that don't configure one.
+    pub nonce_cap: NonceCap,
+    /// Total pending + inmempool transactions this relayer may hold at once before an incoming
+    /// transaction must outscore the weakest queued one to be admitted.
+    pub per_relayer_max_inflight: usize,
+    /// This node's identity and the fencing token it was handed when it claimed `relayer`, so the
+    /// send path can detect the lease being reclaimed by another node and abort cleanly.
+    pub node_id: NodeId,
+    pub lease_epoch: i64,
 }
 
 impl TransactionsQueueSetup {
@@ -36,6 +46,10 @@ impl TransactionsQueueSetup {
         safe_proxy_manager: Arc<SafeProxyManager>,
         gas_bump_config: GasBumpBlockConfig,
         max_gas_price_multiplier: u64,
+        nonce_cap: NonceCap,
+        per_relayer_max_inflight: usize,
+        node_id: NodeId,
+        lease_epoch: i64,
     ) -> Self {
         TransactionsQueueSetup {
             relayer,
@@ -47,6 +61,10 @@ impl TransactionsQueueSetup {
             safe_proxy_manager,
             gas_bump_config,
             max_gas_price_multiplier,
+            nonce_cap,
+            per_relayer_max_inflight,
+            node_id,
+            lease_epoch,
         }
     }
 }
@@ -6,11 +6,12 @@ use thiserror::Error;
 use super::{
     SendTransactionGasPriceError, TransactionQueueSendTransactionError, TransactionSentWithRelayer,
 };
-use crate::shared::{bad_request, internal_server_error, not_found, HttpError};
+use crate::shared::{bad_request, forbidden, internal_server_error, not_found, HttpError};
 use crate::transaction::types::TransactionConversionError;
 use crate::{
     postgres::PostgresError,
-    relayer::RelayerId,
+    relayer::{OnchainAllowlistError, RelayerId},
+    shared::common_types::EvmAddress,
     transaction::types::{Transaction, TransactionId, TransactionStatus},
     WalletError,
 };
@@ -47,6 +48,13 @@ impl From<ReplaceTransactionError> for HttpError {
     }
 }
 
+/// Returned by `TransactionsQueue::try_admit_pending_transaction` when a relayer's pending queue
+/// is at its `NonceCap` (always rejected), or at its per-relayer in-flight cap and the incoming
+/// transaction didn't outscore the weakest queued one.
+#[derive(Error, Debug)]
+#[error("Pending queue is full for relayer {0}")]
+pub struct PendingQueueFullError(pub RelayerId);
+
 #[derive(Error, Debug)]
 pub enum AddTransactionError {
     #[error("Transaction could not be saved in DB: {0}")]
@@ -78,6 +86,27 @@ pub enum AddTransactionError {
 
     #[error("Unsupported transaction type: {message}")]
     UnsupportedTransactionType { message: String },
+
+    #[error("{0}")]
+    PendingQueueFull(#[from] PendingQueueFullError),
+
+    #[error("Global in-flight transaction cap reached")]
+    GlobalInflightCapReached,
+
+    #[error("Relayer {0} refuses service to uncertified recipient {1}")]
+    RecipientNotCertified(RelayerId, EvmAddress),
+
+    #[error("Failed to check on-chain allowlist: {0}")]
+    OnchainAllowlistError(#[from] OnchainAllowlistError),
+
+    /// Returned by `add_transactions_batch` when an earlier item in the batch was already
+    /// saved, queued, and webhook-notified before a later item failed - the batch call is not
+    /// atomic, so these transactions stay live in the system and must not be resubmitted.
+    #[error("{} transaction(s) earlier in this batch were already queued before it failed: {source}", queued.len())]
+    BatchPartiallyApplied {
+        queued: Vec<TransactionId>,
+        source: Box<AddTransactionError>,
+    },
 }
 
 impl From<AddTransactionError> for HttpError {
@@ -94,6 +123,31 @@ impl From<AddTransactionError> for HttpError {
             return bad_request(value.to_string());
         }
 
+        if matches!(
+            value,
+            AddTransactionError::PendingQueueFull(_)
+                | AddTransactionError::GlobalInflightCapReached
+        ) {
+            return bad_request(value.to_string());
+        }
+
+        if matches!(value, AddTransactionError::RecipientNotCertified(_, _)) {
+            return forbidden(value.to_string());
+        }
+
+        if let AddTransactionError::BatchPartiallyApplied { queued, source } = value {
+            let (status, message) = HttpError::from(*source);
+            let queued_ids =
+                queued.iter().map(TransactionId::to_string).collect::<Vec<_>>().join(", ");
+            return (
+                status,
+                format!(
+                    "{message} ({} transaction(s) earlier in this batch were already queued and must not be retried: [{queued_ids}])",
+                    queued.len()
+                ),
+            );
+        }
+
         internal_server_error(Some(value.to_string()))
     }
 }
@@ -194,6 +248,12 @@ pub enum ProcessMinedTransactionError {
 
     #[error("Could not read transaction receipt relayer {0} tx - {1} error - {2}")]
     CouldNotGetTransactionReceipt(RelayerId, Transaction, RpcError<TransportErrorKind>),
+
+    #[error("Could not check whether the mined block is still canonical for relayer {0} tx - {1} error - {2}")]
+    CouldNotCheckBlockConfirmation(RelayerId, Transaction, RpcError<TransportErrorKind>),
+
+    #[error("Could not save reorged transaction rollback to the database for relayer {0}: tx {1} - error {2}")]
+    TransactionReorgedNotSavedToDatabase(RelayerId, Transaction, PostgresError),
 }
 
 #[derive(Error, Debug)]
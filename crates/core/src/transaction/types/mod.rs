@@ -19,6 +19,9 @@ pub use transaction_speed::TransactionSpeed;
 mod transaction_status;
 pub use transaction_status::TransactionStatus;
 
+mod transaction_envelope_type;
+pub use transaction_envelope_type::TransactionEnvelopeType;
+
 mod relayer_transaction;
 
 mod transaction;
@@ -26,3 +29,9 @@ pub use transaction::Transaction;
 
 mod transaction_blob;
 pub use transaction_blob::TransactionBlob;
+
+mod scheduled_transaction_id;
+pub use scheduled_transaction_id::ScheduledTransactionId;
+
+mod scheduled_transaction;
+pub use scheduled_transaction::ScheduledTransaction;
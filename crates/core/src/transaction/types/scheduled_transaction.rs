@@ -0,0 +1,65 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ScheduledTransactionId, TransactionData, TransactionSpeed, TransactionValue};
+use crate::{
+    relayer::types::RelayerId,
+    shared::{
+        common_types::EvmAddress,
+        serializers::{
+            deserialize_system_time, deserialize_system_time_option, serialize_system_time,
+            serialize_system_time_option,
+        },
+    },
+};
+
+/// A transaction a relayer is scheduled to send at a future time, either once or on a fixed
+/// interval. `period_in_seconds` being set is what makes a job recurring; `cancelled` covers both
+/// a user-requested cancellation and a one-off job that has already fired.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct ScheduledTransaction {
+    pub id: ScheduledTransactionId,
+
+    #[serde(rename = "relayerId")]
+    pub relayer_id: RelayerId,
+
+    pub to: EvmAddress,
+
+    pub value: TransactionValue,
+
+    pub data: TransactionData,
+
+    pub speed: TransactionSpeed,
+
+    #[serde(rename = "externalId", skip_serializing_if = "Option::is_none", default)]
+    pub external_id: Option<String>,
+
+    #[serde(rename = "periodInSeconds", skip_serializing_if = "Option::is_none", default)]
+    pub period_in_seconds: Option<i64>,
+
+    #[serde(
+        rename = "nextRunAt",
+        serialize_with = "serialize_system_time",
+        deserialize_with = "deserialize_system_time"
+    )]
+    pub next_run_at: SystemTime,
+
+    pub cancelled: bool,
+
+    #[serde(
+        rename = "createdAt",
+        serialize_with = "serialize_system_time_option",
+        deserialize_with = "deserialize_system_time_option",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub created_at: Option<SystemTime>,
+}
+
+impl ScheduledTransaction {
+    /// Whether this job runs again after firing, rather than firing once and being done.
+    pub fn is_recurring(&self) -> bool {
+        self.period_in_seconds.is_some()
+    }
+}
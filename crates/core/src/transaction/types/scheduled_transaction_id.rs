@@ -0,0 +1,87 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use bytes::BytesMut;
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq)]
+pub struct ScheduledTransactionId(Uuid);
+
+impl Hash for ScheduledTransactionId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl PartialEq for ScheduledTransactionId {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Display for ScheduledTransactionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ScheduledTransactionId {
+    /// Creates a new random scheduled transaction ID.
+    pub fn new() -> ScheduledTransactionId {
+        ScheduledTransactionId(Uuid::new_v4())
+    }
+}
+
+impl Default for ScheduledTransactionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FromSql<'a> for ScheduledTransactionId {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let uuid = Uuid::from_sql(ty, raw)?;
+
+        Ok(ScheduledTransactionId(uuid))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as FromSql>::accepts(ty)
+    }
+}
+
+impl ToSql for ScheduledTransactionId {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send + 'static>> {
+        self.0.to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as FromSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl FromStr for ScheduledTransactionId {
+    type Err = String;
+
+    fn from_str(param: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(param).map(ScheduledTransactionId).map_err(|e| e.to_string())
+    }
+}
+
+impl From<Uuid> for ScheduledTransactionId {
+    fn from(uuid: Uuid) -> Self {
+        ScheduledTransactionId(uuid)
+    }
+}
@@ -2,11 +2,13 @@ use std::{fmt::Display, time::SystemTime};
 
 use alloy::{
     consensus::{
-        TxEip1559, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxLegacy, TypedTransaction,
+        TxEip1559, TxEip2930, TxEip4844, TxEip4844Variant, TxEip4844WithSidecar, TxLegacy,
+        TypedTransaction,
     },
     eips::eip2930::AccessList,
     primitives::TxKind,
 };
+use alloy::network::AnyTransactionReceipt;
 use alloy_eips::eip4844::{
     builder::{SidecarBuilder, SimpleCoder},
     Blob,
@@ -26,6 +28,8 @@ pub enum TransactionConversionError {
     BlobSidecarBuild(String),
     #[error("Gas limit not set")]
     NoGasLimit,
+    #[error("No access list found in transaction")]
+    NoAccessList,
 }
 
 use super::{
@@ -36,12 +40,12 @@ use crate::{
     gas::{
         blob_gas_oracle::BlobGasPriceResult,
         fee_estimator::base::GasPriceResult,
-        types::{GasLimit, MaxFee, MaxPriorityFee},
+        types::{GasLimit, GasPrice, MaxFee, MaxPriorityFee},
     },
     network::types::ChainId,
     relayer::types::RelayerId,
     shared::{
-        common_types::{ApiKey, EvmAddress},
+        common_types::{ApiKey, BlockHash, BlockNumber, EvmAddress},
         serializers::{
             deserialize_system_time, deserialize_system_time_option, serialize_system_time,
             serialize_system_time_option,
@@ -75,6 +79,11 @@ pub struct Transaction {
     #[serde(rename = "txHash", skip_serializing_if = "Option::is_none", default)]
     pub blobs: Option<Vec<Blob>>,
 
+    /// EIP-2930 access list, either set by the caller or generated by `eth_createAccessList`
+    /// before sending. `None` means the transaction is sent without one.
+    #[serde(rename = "accessList", skip_serializing_if = "Option::is_none", default)]
+    pub access_list: Option<AccessList>,
+
     #[serde(rename = "txHash", skip_serializing_if = "Option::is_none", default)]
     pub known_transaction_hash: Option<TransactionHash>,
 
@@ -140,6 +149,65 @@ pub struct Transaction {
     pub from_api_key: ApiKey,
 
     pub external_id: Option<String>,
+
+    /// Number of times the fee escalator has re-signed and rebroadcast this transaction at the
+    /// same nonce with a higher fee. Reset implicitly for a new transaction; never decremented.
+    #[serde(rename = "resubmissionCount", default)]
+    pub resubmission_count: u32,
+
+    /// Absolute ceiling on `max_fee`/legacy `gas_price` the escalator is allowed to bump to for
+    /// this transaction. `None` means only the relayer-wide `max_gas_price` bound applies.
+    #[serde(rename = "maxFeeCap", skip_serializing_if = "Option::is_none", default)]
+    pub max_fee_cap: Option<MaxFee>,
+
+    /// Maximum number of escalation resubmissions before the escalator gives up and marks the
+    /// transaction `FEECAPPED` instead of bumping indefinitely. `None` means no attempt limit.
+    #[serde(rename = "maxResubmissions", skip_serializing_if = "Option::is_none", default)]
+    pub max_resubmissions: Option<u32>,
+
+    /// Gas actually consumed by execution, read from the mining receipt. `None` until the
+    /// transaction is mined; unlike `gas_limit`, this never overwrites the originally-requested
+    /// limit.
+    #[serde(rename = "gasUsed", skip_serializing_if = "Option::is_none", default)]
+    pub gas_used: Option<GasLimit>,
+
+    /// Price actually paid per unit of gas, read from the mining receipt (or, when the node omits
+    /// it, the legacy gas price the transaction was sent with). `None` until the transaction is
+    /// mined.
+    #[serde(rename = "effectiveGasPrice", skip_serializing_if = "Option::is_none", default)]
+    pub effective_gas_price: Option<GasPrice>,
+
+    /// Whether the mined transaction reverted during execution. `None` until mined; `Some(false)`
+    /// for a successful receipt, `Some(true)` when `receipt.status()` reports failure.
+    #[serde(rename = "reverted", skip_serializing_if = "Option::is_none", default)]
+    pub reverted: Option<bool>,
+
+    /// True if this transaction originated from an L1 forced-inclusion event rather than being
+    /// queued and sent by this relayer through the normal API path.
+    #[serde(default)]
+    pub relayed: bool,
+
+    /// Reason the transaction failed - set for both a normal `FAILED` status and a relayed
+    /// transaction's `RELAYEDFAILED` status. `None` until a failure is recorded.
+    #[serde(rename = "failedReason", skip_serializing_if = "Option::is_none", default)]
+    pub failed_reason: Option<String>,
+
+    /// Block the transaction's fate was decided in. For a normally-sent transaction this is set
+    /// once mined; for a relayed transaction it's the block the forced-inclusion event landed in,
+    /// whether or not the transaction itself succeeded.
+    #[serde(rename = "blockNumber", skip_serializing_if = "Option::is_none", default)]
+    pub block_number: Option<BlockNumber>,
+
+    /// Number of the block this transaction was mined in, tracked separately from
+    /// `block_number`; together with `mined_at_block_hash` it is what the confirmation-tracking
+    /// reorg check compares against. Cleared when the transaction is rolled back to inmempool.
+    #[serde(rename = "minedAtBlockNumber", skip_serializing_if = "Option::is_none", default)]
+    pub mined_at_block_number: Option<BlockNumber>,
+
+    /// Hash of the block this transaction was mined in. Compared against the node's current view
+    /// of that height to detect a reorg; cleared when the transaction is rolled back to inmempool.
+    #[serde(rename = "minedAtBlockHash", skip_serializing_if = "Option::is_none", default)]
+    pub mined_at_block_hash: Option<BlockHash>,
 }
 
 impl Display for Transaction {
@@ -157,6 +225,22 @@ impl Transaction {
         self.sent_at.is_some()
     }
 
+    /// Effective price actually paid per unit of gas for this mined transaction.
+    ///
+    /// Reads the receipt's own `effective_gas_price` when the node supplies one. Some nodes omit
+    /// it on legacy (pre-EIP-1559) chains, where the gas price bid for the transaction is itself
+    /// the effective price since there is no separate base fee to add a priority fee on top of.
+    pub fn effective_gas_price_from_receipt(&self, receipt: &AnyTransactionReceipt) -> GasPrice {
+        if receipt.effective_gas_price != 0 {
+            return GasPrice::new(receipt.effective_gas_price);
+        }
+
+        self.sent_with_gas
+            .as_ref()
+            .map(|gas| gas.legacy_gas_price())
+            .unwrap_or_else(|| GasPrice::new(0))
+    }
+
     fn is_eip1559(&self) -> bool {
         self.sent_with_max_priority_fee_per_gas.is_some()
             && self.sent_with_max_fee_per_gas.is_some()
@@ -192,7 +276,43 @@ impl Transaction {
             max_priority_fee_per_gas: gas_price_result.max_priority_fee.clone().into(),
             max_fee_per_gas: gas_price_result.max_fee.into(),
             chain_id: self.chain_id.into(),
-            access_list: AccessList::default(),
+            access_list: self.access_list.clone().unwrap_or_default(),
+        }))
+    }
+
+    /// Converts this transaction to an EIP-2930 (type-1) typed transaction.
+    ///
+    /// Unlike the EIP-1559/blob converters, an access list is required rather than defaulted,
+    /// since a type-1 transaction with an empty list is just a worse legacy transaction.
+    ///
+    /// # Arguments
+    /// * `override_gas_price` - Optional gas price to override stored values
+    pub fn to_eip2930_typed_transaction(
+        &self,
+        override_gas_price: Option<&GasPriceResult>,
+    ) -> Result<TypedTransaction, TransactionConversionError> {
+        let gas_price_result = match override_gas_price {
+            Some(gas_price) => gas_price.legacy_gas_price(),
+            None => self
+                .sent_with_gas
+                .as_ref()
+                .ok_or(TransactionConversionError::NoGasPrice)?
+                .legacy_gas_price(),
+        };
+
+        let gas_limit = self.gas_limit.ok_or(TransactionConversionError::NoGasLimit)?;
+        let access_list =
+            self.access_list.clone().ok_or(TransactionConversionError::NoAccessList)?;
+
+        Ok(TypedTransaction::Eip2930(TxEip2930 {
+            to: TxKind::Call(self.to.into()),
+            value: self.value.clone().into(),
+            input: self.data.clone().into(),
+            gas_limit: gas_limit.into(),
+            nonce: self.nonce.into(),
+            gas_price: gas_price_result.into(),
+            chain_id: self.chain_id.into(),
+            access_list,
         }))
     }
 
@@ -262,7 +382,7 @@ impl Transaction {
             gas_limit: 210000,
             to: self.to.into(),
             value: self.value.clone().into(),
-            access_list: Default::default(),
+            access_list: self.access_list.clone().unwrap_or_default(),
             blob_versioned_hashes,
             max_fee_per_blob_gas: blob_gas_price.into(),
             input: self.data.clone().into(),
@@ -280,4 +400,27 @@ impl Transaction {
     pub fn is_blob_transaction(&self) -> bool {
         self.blobs.is_some()
     }
+
+    /// Converts this transaction to the typed transaction matching the chain's capabilities.
+    ///
+    /// Blob transactions are always EIP-4844; otherwise this emits an EIP-1559 (type-2)
+    /// transaction when the chain supports the London fork, or a legacy (type-0) transaction
+    /// when it doesn't, so a relayer on a non-1559 chain never produces a malformed envelope.
+    ///
+    /// # Arguments
+    /// * `supports_eip1559` - Whether the destination chain supports EIP-1559
+    /// * `override_gas_price` - Optional gas price to override stored values
+    pub fn to_typed_transaction(
+        &self,
+        supports_eip1559: bool,
+        override_gas_price: Option<&GasPriceResult>,
+    ) -> Result<TypedTransaction, TransactionConversionError> {
+        if self.is_blob_transaction() {
+            self.to_blob_typed_transaction(override_gas_price, None)
+        } else if supports_eip1559 {
+            self.to_eip1559_typed_transaction(override_gas_price)
+        } else {
+            self.to_legacy_typed_transaction(override_gas_price)
+        }
+    }
 }
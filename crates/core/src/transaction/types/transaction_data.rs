@@ -30,6 +30,10 @@ impl TransactionData {
         self.0
     }
 
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
     pub fn hex(&self) -> String {
         hex::encode(&self.0)
     }
@@ -0,0 +1,104 @@
+use core::fmt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::from_utf8,
+};
+
+use bytes::BytesMut;
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::{FromSql, IsNull, Type};
+
+use crate::postgres::ToSql;
+
+/// A relayer's preferred transaction envelope - which typed-transaction format it builds and
+/// signs with when sending. Generalizes the old plain `eip_1559_enabled` boolean so a relayer
+/// can also be pinned to EIP-2930 access-list transactions.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum TransactionEnvelopeType {
+    LEGACY,
+    EIP2930,
+    EIP1559,
+}
+
+impl Display for TransactionEnvelopeType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
+
+impl TransactionEnvelopeType {
+    pub fn format(&self) -> String {
+        match self {
+            TransactionEnvelopeType::LEGACY => "LEGACY".to_string(),
+            TransactionEnvelopeType::EIP2930 => "EIP2930".to_string(),
+            TransactionEnvelopeType::EIP1559 => "EIP1559".to_string(),
+        }
+    }
+}
+
+impl<'a> FromSql<'a> for TransactionEnvelopeType {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if ty.name() == "tx_envelope_type" {
+            let envelope_type =
+                from_utf8(raw).map_err(|err| format!("Invalid UTF-8 sequence: {}", err))?;
+
+            match envelope_type {
+                "LEGACY" => Ok(TransactionEnvelopeType::LEGACY),
+                "EIP2930" => Ok(TransactionEnvelopeType::EIP2930),
+                "EIP1559" => Ok(TransactionEnvelopeType::EIP1559),
+                _ => Err(format!("Unknown TransactionEnvelopeType: {}", envelope_type).into()),
+            }
+        } else if *ty == Type::TEXT
+            || *ty == Type::CHAR
+            || *ty == Type::VARCHAR
+            || *ty == Type::BPCHAR
+        {
+            let envelope_type =
+                from_utf8(raw).map_err(|err| format!("Invalid UTF-8 sequence: {}", err))?;
+
+            match envelope_type {
+                "LEGACY" => Ok(TransactionEnvelopeType::LEGACY),
+                "EIP2930" => Ok(TransactionEnvelopeType::EIP2930),
+                "EIP1559" => Ok(TransactionEnvelopeType::EIP1559),
+                _ => Err(format!("Unknown TransactionEnvelopeType: {}", envelope_type).into()),
+            }
+        } else {
+            Err(format!("Unexpected type for TransactionEnvelopeType: {}", ty).into())
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        (*ty == Type::TEXT || *ty == Type::CHAR || *ty == Type::VARCHAR || *ty == Type::BPCHAR)
+            || (ty.name() == "tx_envelope_type")
+    }
+}
+
+impl ToSql for TransactionEnvelopeType {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if !<Self as ToSql>::accepts(ty) {
+            return Err(format!("Unexpected type for TransactionEnvelopeType: {}", ty).into());
+        }
+
+        let envelope_type_str = match self {
+            TransactionEnvelopeType::LEGACY => "LEGACY",
+            TransactionEnvelopeType::EIP2930 => "EIP2930",
+            TransactionEnvelopeType::EIP1559 => "EIP1559",
+        };
+
+        out.extend_from_slice(envelope_type_str.as_bytes());
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        (*ty == Type::TEXT || *ty == Type::CHAR || *ty == Type::VARCHAR || *ty == Type::BPCHAR)
+            || (ty.name() == "tx_envelope_type")
+    }
+
+    tokio_postgres::types::to_sql_checked!();
+}
@@ -17,6 +17,14 @@ pub enum TransactionStatus {
     CONFIRMED,
     FAILED,
     EXPIRED,
+    /// The fee escalator hit its resubmission ceiling (max attempts or max fee cap) before the
+    /// transaction got mined. It is no longer being bumped or rebroadcast automatically.
+    FEECAPPED,
+    /// A `relayed` transaction (one submitted via an L1 forced-inclusion event rather than this
+    /// relayer's normal send path) was rejected, reverted, or otherwise failed to land. The block
+    /// it was decided in and the failure reason are recorded on the transaction's `block_number`
+    /// and `failed_reason` fields.
+    RELAYEDFAILED,
 }
 
 impl Display for TransactionStatus {
@@ -34,6 +42,8 @@ impl TransactionStatus {
             TransactionStatus::CONFIRMED => "CONFIRMED".to_string(),
             TransactionStatus::FAILED => "FAILED".to_string(),
             TransactionStatus::EXPIRED => "EXPIRED".to_string(),
+            TransactionStatus::FEECAPPED => "FEECAPPED".to_string(),
+            TransactionStatus::RELAYEDFAILED => "RELAYEDFAILED".to_string(),
         }
     }
 }
@@ -51,6 +61,8 @@ impl<'a> FromSql<'a> for TransactionStatus {
                 "CONFIRMED" => Ok(TransactionStatus::CONFIRMED),
                 "FAILED" => Ok(TransactionStatus::FAILED),
                 "EXPIRED" => Ok(TransactionStatus::EXPIRED),
+                "FEECAPPED" => Ok(TransactionStatus::FEECAPPED),
+                "RELAYEDFAILED" => Ok(TransactionStatus::RELAYEDFAILED),
                 _ => Err(format!("Unknown TransactionStatus: {}", status).into()),
             }
         } else if *ty == Type::TEXT
@@ -68,6 +80,8 @@ impl<'a> FromSql<'a> for TransactionStatus {
                 "CONFIRMED" => Ok(TransactionStatus::CONFIRMED),
                 "FAILED" => Ok(TransactionStatus::FAILED),
                 "EXPIRED" => Ok(TransactionStatus::EXPIRED),
+                "FEECAPPED" => Ok(TransactionStatus::FEECAPPED),
+                "RELAYEDFAILED" => Ok(TransactionStatus::RELAYEDFAILED),
                 _ => Err(format!("Unknown TransactionStatus: {}", status).into()),
             }
         } else {
@@ -98,6 +112,8 @@ impl ToSql for TransactionStatus {
             TransactionStatus::CONFIRMED => "CONFIRMED",
             TransactionStatus::FAILED => "FAILED",
             TransactionStatus::EXPIRED => "EXPIRED",
+            TransactionStatus::FEECAPPED => "FEECAPPED",
+            TransactionStatus::RELAYEDFAILED => "RELAYEDFAILED",
         };
 
         out.extend_from_slice(status_str.as_bytes());
@@ -14,20 +14,25 @@ use crate::{
     app_state::AppState,
     authentication::{guards::admin_jwt_guard, types::JwtRole},
     rrelayer_error,
-    shared::common_types::{EvmAddress, PagingContext, PagingQuery, PagingResult},
+    shared::common_types::{
+        CursorPagingContext, CursorPagingQuery, CursorPagingResult, EvmAddress,
+    },
 };
 
 // TODO! add paged caching
 async fn get_users(
     State(state): State<Arc<AppState>>,
-    Query(paging): Query<PagingQuery>,
-) -> Result<Json<PagingResult<User>>, StatusCode> {
-    state.db.get_users(&PagingContext::new(paging.limit, paging.offset)).await.map(Json).map_err(
-        |e| {
+    Query(paging): Query<CursorPagingQuery>,
+) -> Result<Json<CursorPagingResult<User>>, StatusCode> {
+    state
+        .db
+        .get_users(&CursorPagingContext::new(paging.cursor, paging.limit))
+        .await
+        .map(Json)
+        .map_err(|e| {
             rrelayer_error!("{}", e);
             StatusCode::INTERNAL_SERVER_ERROR
-        },
-    )
+        })
 }
 
 #[derive(Debug, Deserialize)]
@@ -41,7 +46,17 @@ async fn edit_user(
     State(state): State<Arc<AppState>>,
     Json(edit_user_request): Json<EditUserRequest>,
 ) -> StatusCode {
-    match state.db.edit_user(&edit_user_request.user, &edit_user_request.new_role).await {
+    match has_contract_code(&state, &edit_user_request.user).await {
+        Ok(true) => return StatusCode::BAD_REQUEST,
+        Ok(false) => {}
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR,
+    }
+
+    match state
+        .db
+        .edit_user(&edit_user_request.user, &edit_user_request.new_role)
+        .await
+    {
         Ok(_) => StatusCode::NO_CONTENT,
         Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
     }
@@ -53,11 +68,34 @@ struct AddUserRequest {
     pub role: JwtRole,
 }
 
+/// EIP-3607: an address with deployed contract code can never produce the ECDSA signature the
+/// authentication challenge expects, so registering one as a user would silently lock it out.
+/// Checked against the first configured network's provider, since a `User` isn't chain-scoped.
+async fn has_contract_code(state: &Arc<AppState>, address: &EvmAddress) -> Result<bool, String> {
+    match state.evm_providers.first() {
+        Some(provider) => provider
+            .has_contract_code(address)
+            .await
+            .map_err(|e| e.to_string()),
+        None => Ok(false),
+    }
+}
+
 async fn add_user(
     State(state): State<Arc<AppState>>,
     Json(add_user_request): Json<AddUserRequest>,
 ) -> StatusCode {
-    match state.db.add_user(&add_user_request.user, &add_user_request.role).await {
+    match has_contract_code(&state, &add_user_request.user).await {
+        Ok(true) => return StatusCode::BAD_REQUEST,
+        Ok(false) => {}
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR,
+    }
+
+    match state
+        .db
+        .add_user(&add_user_request.user, &add_user_request.role)
+        .await
+    {
         Ok(_) => StatusCode::NO_CONTENT,
         Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
     }
@@ -1,7 +1,7 @@
 use super::builders::build_user;
 use crate::{
     postgres::{PostgresClient, PostgresError},
-    shared::common_types::{EvmAddress, PagingContext, PagingResult},
+    shared::common_types::{CursorPagingContext, CursorPagingResult, EvmAddress},
     user::types::User,
 };
 
@@ -24,26 +24,55 @@ impl PostgresClient {
         }
     }
 
+    /// Lists users ordered by address using keyset pagination, instead of `LIMIT`/`OFFSET`,
+    /// which can skip or duplicate rows under concurrent inserts. An absent cursor fetches the
+    /// first page; otherwise only addresses strictly greater than the cursor are returned.
     pub async fn get_users(
         &self,
-        paging_context: &PagingContext,
-    ) -> Result<PagingResult<User>, PostgresError> {
-        let rows = self
-            .query(
-                "
-                    SELECT *
-                    FROM authentication.user_access
-                    LIMIT $1
-                    OFFSET $2;
-                ",
-                &[&(paging_context.limit as i64), &(paging_context.offset as i64)],
-            )
-            .await?;
+        paging_context: &CursorPagingContext,
+    ) -> Result<CursorPagingResult<User>, PostgresError> {
+        let limit = paging_context.limit as i64;
+
+        let rows = match &paging_context.cursor {
+            Some(cursor) => {
+                let cursor_address: EvmAddress = cursor
+                    .parse()
+                    .map_err(|_| PostgresError::Custom("Invalid pagination cursor".to_string()))?;
+
+                self.query(
+                    "
+                        SELECT *
+                        FROM authentication.user_access
+                        WHERE address > $1
+                        ORDER BY address
+                        LIMIT $2;
+                    ",
+                    &[&cursor_address, &limit],
+                )
+                .await?
+            }
+            None => {
+                self.query(
+                    "
+                        SELECT *
+                        FROM authentication.user_access
+                        ORDER BY address
+                        LIMIT $1;
+                    ",
+                    &[&limit],
+                )
+                .await?
+            }
+        };
 
         let results: Vec<User> = rows.iter().map(build_user).collect();
 
-        let result_count = results.len();
+        let next_cursor = if results.len() as i64 == limit {
+            results.last().map(|user| user.address.hex())
+        } else {
+            None
+        };
 
-        Ok(PagingResult::new(results, paging_context.next(result_count), paging_context.previous()))
+        Ok(CursorPagingResult::new(results, next_cursor))
     }
 }
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::HeaderMap, Json};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{app_state::AppState, shared::HttpError};
+
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryMetric {
+    pub id: Uuid,
+    pub endpoint: String,
+    pub attempts: u32,
+    pub max_retries: u32,
+    pub completed: bool,
+    pub failed: bool,
+    #[serde(rename = "nextRetryAt")]
+    pub next_retry_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookMetricsResult {
+    /// Deliveries currently held in memory, including ones already completed or failed (see each
+    /// delivery's `completed`/`failed` flags)
+    #[serde(rename = "pendingCount")]
+    pub pending_count: usize,
+    /// Deliveries that exhausted all retries and were moved to the dead-letter queue
+    #[serde(rename = "abandonedCount")]
+    pub abandoned_count: i64,
+    pub deliveries: Vec<WebhookDeliveryMetric>,
+}
+
+/// Returns a snapshot of in-flight webhook delivery state (per-delivery attempts/status/next
+/// retry time) plus the total count of deliveries sitting in the dead-letter queue.
+pub async fn get_webhook_metrics(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<WebhookMetricsResult>, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let abandoned_count = state.db.count_abandoned_webhook_deliveries().await?;
+
+    let deliveries = if let Some(webhook_manager) = &state.webhook_manager {
+        let manager = webhook_manager.lock().await;
+        let pending = manager.pending_deliveries.read().await;
+        pending
+            .values()
+            .map(|delivery| WebhookDeliveryMetric {
+                id: delivery.id,
+                endpoint: delivery.webhook_config.endpoint.clone(),
+                attempts: delivery.attempts,
+                max_retries: delivery.max_retries,
+                completed: delivery.completed,
+                failed: delivery.failed,
+                next_retry_at: delivery.next_retry_at.map(DateTime::<Utc>::from),
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    Ok(Json(WebhookMetricsResult {
+        pending_count: deliveries.len(),
+        abandoned_count,
+        deliveries,
+    }))
+}
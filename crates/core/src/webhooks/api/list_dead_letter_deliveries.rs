@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    Json,
+};
+
+use crate::{
+    app_state::AppState,
+    shared::{
+        common_types::{PagingContext, PagingQuery, PagingResult},
+        HttpError,
+    },
+    webhooks::WebhookDeliveryRecord,
+};
+
+/// Lists webhook deliveries that exhausted all retry attempts and were abandoned - the
+/// dead-letter queue - most recently abandoned first.
+pub async fn list_dead_letter_deliveries(
+    State(state): State<Arc<AppState>>,
+    Query(paging): Query<PagingQuery>,
+    headers: HeaderMap,
+) -> Result<Json<PagingResult<WebhookDeliveryRecord>>, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let paging_context = PagingContext::new(paging.limit, paging.offset);
+    let result = state.db.list_abandoned_webhook_deliveries(&paging_context).await?;
+
+    Ok(Json(result))
+}
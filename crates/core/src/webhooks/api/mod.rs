@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::app_state::AppState;
+
+mod get_webhook_metrics;
+mod list_dead_letter_deliveries;
+mod redrive_webhook_delivery;
+
+/// Creates the admin-only webhook delivery routes: listing and redriving dead-lettered
+/// (abandoned) deliveries, and a metrics snapshot of in-flight delivery state.
+pub fn create_webhooks_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route(
+            "/dead-letter",
+            get(list_dead_letter_deliveries::list_dead_letter_deliveries),
+        )
+        .route(
+            "/dead-letter/:id/redrive",
+            post(redrive_webhook_delivery::redrive_webhook_delivery),
+        )
+        .route("/metrics", get(get_webhook_metrics::get_webhook_metrics))
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use uuid::Uuid;
+
+use crate::{
+    app_state::AppState,
+    shared::{bad_request, HttpError},
+};
+
+/// Redrives a single dead-lettered webhook delivery, re-queuing it for immediate delivery.
+pub async fn redrive_webhook_delivery(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<StatusCode, HttpError> {
+    state.validate_basic_auth_valid(&headers)?;
+
+    let webhook_manager = state
+        .webhook_manager
+        .as_ref()
+        .ok_or_else(|| bad_request("Webhooks are not configured".to_string()))?;
+
+    webhook_manager.lock().await.redrive_delivery(id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
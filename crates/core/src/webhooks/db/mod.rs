@@ -0,0 +1,8 @@
+mod write;
+pub use write::{
+    CreateWebhookDeliveryRequest, UpdateWebhookDeliveryRequest, WebhookDeliveryEventType,
+    WebhookDeliveryStatus,
+};
+
+mod read;
+pub use read::WebhookDeliveryRecord;
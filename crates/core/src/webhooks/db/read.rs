@@ -0,0 +1,130 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::write::{WebhookDeliveryEventType, WebhookDeliveryStatus};
+use crate::{
+    network::ChainId,
+    postgres::{PostgresClient, PostgresError},
+    relayer::RelayerId,
+    shared::common_types::{PagingContext, PagingResult},
+    transaction::types::TransactionId,
+};
+
+/// A single row from `webhook.delivery_history`, as read back for the dead-letter/metrics API -
+/// the in-memory `WebhookDelivery` only exists for the lifetime of a delivery attempt, so this is
+/// the durable view of what was sent, retried and (if exhausted) abandoned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDeliveryRecord {
+    pub id: Uuid,
+    pub webhook_endpoint: String,
+    pub event_type: WebhookDeliveryEventType,
+    pub status: WebhookDeliveryStatus,
+    pub transaction_id: Option<TransactionId>,
+    pub relayer_id: Option<RelayerId>,
+    pub chain_id: Option<ChainId>,
+    pub attempts: i32,
+    pub max_retries: i32,
+    pub payload: serde_json::Value,
+    pub http_status_code: Option<i32>,
+    pub response_body: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub first_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub abandoned_at: Option<DateTime<Utc>>,
+    pub total_duration_ms: Option<i64>,
+}
+
+const WEBHOOK_DELIVERY_COLUMNS: &str = "
+    id, webhook_endpoint, event_type, status, transaction_id, relayer_id, chain_id,
+    attempts, max_retries, payload, http_status_code, response_body, error_message,
+    created_at, first_attempt_at, last_attempt_at, delivered_at, abandoned_at, total_duration_ms
+";
+
+fn build_webhook_delivery_record(row: &tokio_postgres::Row) -> WebhookDeliveryRecord {
+    WebhookDeliveryRecord {
+        id: row.get("id"),
+        webhook_endpoint: row.get("webhook_endpoint"),
+        event_type: row.get("event_type"),
+        status: row.get("status"),
+        transaction_id: row.get("transaction_id"),
+        relayer_id: row.get("relayer_id"),
+        chain_id: row.get("chain_id"),
+        attempts: row.get("attempts"),
+        max_retries: row.get("max_retries"),
+        payload: row.get("payload"),
+        http_status_code: row.get("http_status_code"),
+        response_body: row.get("response_body"),
+        error_message: row.get("error_message"),
+        created_at: row.get("created_at"),
+        first_attempt_at: row.get("first_attempt_at"),
+        last_attempt_at: row.get("last_attempt_at"),
+        delivered_at: row.get("delivered_at"),
+        abandoned_at: row.get("abandoned_at"),
+        total_duration_ms: row.get("total_duration_ms"),
+    }
+}
+
+impl PostgresClient {
+    /// Lists deliveries that were abandoned after exhausting all retry attempts - the dead-letter
+    /// queue - most recently abandoned first.
+    pub async fn list_abandoned_webhook_deliveries(
+        &self,
+        paging_context: &PagingContext,
+    ) -> Result<PagingResult<WebhookDeliveryRecord>, PostgresError> {
+        let query = format!(
+            "
+                SELECT {WEBHOOK_DELIVERY_COLUMNS}
+                FROM webhook.delivery_history
+                WHERE status = 'ABANDONED'
+                ORDER BY abandoned_at DESC
+                LIMIT $1
+                OFFSET $2;
+            "
+        );
+
+        let rows = self
+            .query(&query, &[&(paging_context.limit as i64), &(paging_context.offset as i64)])
+            .await?;
+
+        let results: Vec<WebhookDeliveryRecord> =
+            rows.iter().map(build_webhook_delivery_record).collect();
+        let result_count = results.len();
+
+        Ok(PagingResult::new(results, paging_context.next(result_count), paging_context.previous()))
+    }
+
+    /// Fetches a single delivery by id, for redriving a dead-lettered webhook or inspecting its
+    /// history.
+    pub async fn get_webhook_delivery_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<WebhookDeliveryRecord>, PostgresError> {
+        let query = format!(
+            "
+                SELECT {WEBHOOK_DELIVERY_COLUMNS}
+                FROM webhook.delivery_history
+                WHERE id = $1;
+            "
+        );
+
+        let row = self.query_one_or_none(&query, &[id]).await?;
+
+        Ok(row.as_ref().map(build_webhook_delivery_record))
+    }
+
+    /// Counts deliveries currently sitting in the dead-letter queue, for the webhook metrics
+    /// endpoint.
+    pub async fn count_abandoned_webhook_deliveries(&self) -> Result<i64, PostgresError> {
+        let row = self
+            .query_one(
+                "SELECT COUNT(*) AS count FROM webhook.delivery_history WHERE status = 'ABANDONED';",
+                &[],
+            )
+            .await?;
+
+        Ok(row.get("count"))
+    }
+}
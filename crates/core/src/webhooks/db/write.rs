@@ -175,6 +175,28 @@ impl From<WebhookEventType> for WebhookDeliveryEventType {
     }
 }
 
+impl From<WebhookDeliveryEventType> for WebhookEventType {
+    fn from(event_type: WebhookDeliveryEventType) -> Self {
+        match event_type {
+            WebhookDeliveryEventType::TransactionQueued => WebhookEventType::TransactionQueued,
+            WebhookDeliveryEventType::TransactionSent => WebhookEventType::TransactionSent,
+            WebhookDeliveryEventType::TransactionMined => WebhookEventType::TransactionMined,
+            WebhookDeliveryEventType::TransactionConfirmed => {
+                WebhookEventType::TransactionConfirmed
+            }
+            WebhookDeliveryEventType::TransactionFailed => WebhookEventType::TransactionFailed,
+            WebhookDeliveryEventType::TransactionExpired => WebhookEventType::TransactionExpired,
+            WebhookDeliveryEventType::TransactionCancelled => {
+                WebhookEventType::TransactionCancelled
+            }
+            WebhookDeliveryEventType::TransactionReplaced => WebhookEventType::TransactionReplaced,
+            WebhookDeliveryEventType::TextSigned => WebhookEventType::TextSigned,
+            WebhookDeliveryEventType::TypedDataSigned => WebhookEventType::TypedDataSigned,
+            WebhookDeliveryEventType::LowBalance => WebhookEventType::LowBalance,
+        }
+    }
+}
+
 impl<'a> FromSql<'a> for WebhookDeliveryEventType {
     fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
         if ty.name() == "event_type" {
@@ -1,38 +1,61 @@
 use alloy::primitives::U256;
-use std::{
-    collections::HashMap,
-    sync::Arc,
-    time::{Duration, SystemTime},
-};
-use tokio::{
-    sync::RwLock,
-    time::{interval, Interval},
-};
+use std::{collections::HashMap, sync::Arc, time::SystemTime};
+use thiserror::Error;
+use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
 use super::{
+    db::WebhookDeliveryStatus,
     payload::{WebhookPayload, WebhookSigningPayload},
     sender::WebhookSender,
     types::{WebhookDelivery, WebhookDeliveryConfig, WebhookEventType, WebhookFilter},
 };
 use crate::relayer::RelayerId;
 use crate::{
-    network::ChainId, postgres::PostgresClient, transaction::types::Transaction,
-    yaml::WebhookConfig, SetupConfig,
+    network::ChainId,
+    postgres::{PostgresClient, PostgresError},
+    shared::{bad_request, not_found, HttpError},
+    transaction::types::Transaction,
+    yaml::WebhookConfig,
+    SetupConfig,
 };
 
+#[derive(Error, Debug)]
+pub enum RedriveWebhookDeliveryError {
+    #[error("No webhook delivery found with id {0}")]
+    NotFound(Uuid),
+
+    #[error("Webhook delivery {0} is not abandoned, so it cannot be redriven")]
+    NotAbandoned(Uuid),
+
+    #[error("No configured webhook endpoint matches {0} any more, so it cannot be redriven")]
+    NoMatchingWebhookConfig(String),
+
+    #[error("{0}")]
+    Database(#[from] PostgresError),
+}
+
+impl From<RedriveWebhookDeliveryError> for HttpError {
+    fn from(error: RedriveWebhookDeliveryError) -> Self {
+        match error {
+            RedriveWebhookDeliveryError::NotFound(_) => {
+                not_found("Webhook delivery could not be found".to_string())
+            }
+            RedriveWebhookDeliveryError::NotAbandoned(_)
+            | RedriveWebhookDeliveryError::NoMatchingWebhookConfig(_) => {
+                bad_request(error.to_string())
+            }
+            RedriveWebhookDeliveryError::Database(e) => e.into(),
+        }
+    }
+}
+
 pub struct WebhookManager {
     pub pending_deliveries: Arc<RwLock<HashMap<Uuid, WebhookDelivery>>>,
     pub sender: WebhookSender,
     webhook_configs: Vec<WebhookConfig>,
     network_names: Arc<RwLock<HashMap<ChainId, String>>>,
-    // TODO: REVIEW
-    #[allow(dead_code)]
-    cleanup_interval: Interval,
-    // TODO: REVIEW
-    #[allow(dead_code)]
-    retry_interval: Interval,
 }
 
 impl WebhookManager {
@@ -62,8 +85,6 @@ impl WebhookManager {
             sender,
             webhook_configs,
             network_names: Arc::new(RwLock::new(network_names)),
-            cleanup_interval: interval(Duration::from_secs(300)),
-            retry_interval: interval(Duration::from_secs(30)),
         })
     }
 
@@ -328,6 +349,54 @@ impl WebhookManager {
     pub async fn pending_count(&self) -> usize {
         self.pending_deliveries.read().await.len()
     }
+
+    /// Redrives a dead-lettered (abandoned) delivery: re-matches it against the currently
+    /// configured webhooks by endpoint, resets its attempt count, and queues it for immediate
+    /// delivery. The shared secret isn't persisted in `webhook.delivery_history`, so the matching
+    /// live `WebhookConfig` supplies it rather than the historic record.
+    pub async fn redrive_delivery(
+        &self,
+        id: Uuid,
+    ) -> Result<(), RedriveWebhookDeliveryError> {
+        let record = self
+            .sender
+            .db
+            .get_webhook_delivery_by_id(&id)
+            .await?
+            .ok_or(RedriveWebhookDeliveryError::NotFound(id))?;
+
+        if record.status != WebhookDeliveryStatus::Abandoned {
+            return Err(RedriveWebhookDeliveryError::NotAbandoned(id));
+        }
+
+        let webhook_config = self
+            .webhook_configs
+            .iter()
+            .find(|config| config.endpoint == record.webhook_endpoint)
+            .cloned()
+            .ok_or(RedriveWebhookDeliveryError::NoMatchingWebhookConfig(
+                record.webhook_endpoint.clone(),
+            ))?;
+
+        let delivery = WebhookDelivery::new(
+            webhook_config,
+            WebhookEventType::from(record.event_type),
+            record.payload,
+        );
+
+        info!("Redriving abandoned webhook delivery {} as new delivery {}", id, delivery.id);
+
+        self.pending_deliveries.write().await.insert(delivery.id, delivery);
+
+        tokio::spawn({
+            let manager = self.clone();
+            async move {
+                manager.process_ready_deliveries().await;
+            }
+        });
+
+        Ok(())
+    }
 }
 
 impl Clone for WebhookManager {
@@ -337,8 +406,6 @@ impl Clone for WebhookManager {
             sender: self.sender.clone(),
             webhook_configs: self.webhook_configs.clone(),
             network_names: self.network_names.clone(),
-            cleanup_interval: interval(Duration::from_secs(300)),
-            retry_interval: interval(Duration::from_secs(30)),
         }
     }
 }
@@ -391,6 +458,31 @@ impl WebhookManager {
         self.queue_webhook_with_payload(new_transaction, payload).await;
     }
 
+    pub async fn on_transaction_stuck(&self, transaction: &Transaction) {
+        let payload = WebhookPayload::transaction_stuck(transaction);
+        self.queue_webhook_with_payload(transaction, payload).await;
+    }
+
+    pub async fn on_transaction_rescued(
+        &self,
+        rescued_transaction: &Transaction,
+        original_transaction: &Transaction,
+    ) {
+        let payload =
+            WebhookPayload::transaction_rescued(rescued_transaction, original_transaction);
+        self.queue_webhook_with_payload(rescued_transaction, payload).await;
+    }
+
+    pub async fn on_transaction_reorged(
+        &self,
+        rolled_back_transaction: &Transaction,
+        mined_transaction: &Transaction,
+    ) {
+        let payload =
+            WebhookPayload::transaction_reorged(rolled_back_transaction, mined_transaction);
+        self.queue_webhook_with_payload(rolled_back_transaction, payload).await;
+    }
+
     pub async fn on_text_signed(
         &self,
         relayer_id: &RelayerId,
@@ -422,6 +514,82 @@ impl WebhookManager {
         self.queue_signing_webhook(relayer_id, chain_id, payload).await;
     }
 
+    pub async fn on_batch_queued(
+        &self,
+        relayer_id: RelayerId,
+        chain_id: ChainId,
+        batch_id: Uuid,
+        transactions: &[Transaction],
+    ) {
+        let payload =
+            super::payload::WebhookBatchPayload::batch_queued(relayer_id, chain_id, batch_id, transactions);
+        self.queue_batch_webhook(&relayer_id, chain_id, payload).await;
+    }
+
+    async fn queue_batch_webhook(
+        &self,
+        relayer_id: &RelayerId,
+        chain_id: ChainId,
+        payload: super::payload::WebhookBatchPayload,
+    ) {
+        if self.webhook_configs.is_empty() {
+            info!("No webhooks configured, skipping batch webhook for relayer {}", relayer_id);
+            return;
+        }
+
+        let network_names = self.network_names.read().await;
+        let chain_name =
+            network_names.get(&chain_id).cloned().unwrap_or_else(|| chain_id.to_string());
+
+        let payload_json = match payload.to_json_value() {
+            Ok(json) => json,
+            Err(e) => {
+                error!("Failed to serialize batch webhook payload for relayer {}: {}", relayer_id, e);
+                return;
+            }
+        };
+
+        let mut deliveries_to_queue = Vec::new();
+
+        for webhook_config in &self.webhook_configs {
+            if webhook_config.networks.is_empty() || webhook_config.networks.contains(&chain_name) {
+                let delivery = WebhookDelivery::new(
+                    webhook_config.clone(),
+                    payload.event_type.clone(),
+                    payload_json.clone(),
+                );
+                deliveries_to_queue.push(delivery);
+            }
+        }
+
+        if deliveries_to_queue.is_empty() {
+            debug!(
+                "No webhooks matched filters for batch queued by relayer {} on chain {}",
+                relayer_id, chain_name
+            );
+            return;
+        }
+
+        info!(
+            "Queuing {} batch webhooks for relayer {} on chain {}",
+            deliveries_to_queue.len(),
+            relayer_id,
+            chain_name
+        );
+
+        let mut pending = self.pending_deliveries.write().await;
+        for delivery in deliveries_to_queue {
+            pending.insert(delivery.id, delivery);
+        }
+
+        tokio::spawn({
+            let manager = self.clone();
+            async move {
+                manager.process_ready_deliveries().await;
+            }
+        });
+    }
+
     /// Get webhook configurations that should receive low balance alerts for a specific chain
     pub fn get_webhook_configs_for_chain(&self, chain_id: &ChainId) -> Vec<&WebhookConfig> {
         self.webhook_configs
@@ -1,10 +1,14 @@
 mod db;
+pub use db::WebhookDeliveryRecord;
 
 mod manager;
-pub use manager::WebhookManager;
+pub use manager::{RedriveWebhookDeliveryError, WebhookManager};
 
 mod low_balance_payload;
 mod payload;
 pub use low_balance_payload::WebhookLowBalancePayload;
 mod sender;
 mod types;
+
+mod api;
+pub use api::create_webhooks_routes;
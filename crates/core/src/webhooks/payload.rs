@@ -11,6 +11,7 @@ use crate::{
 use alloy::{network::AnyTransactionReceipt, primitives::PrimitiveSignature};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use super::types::WebhookEventType;
 
@@ -214,6 +215,50 @@ impl WebhookSigningPayload {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookBatchPayload {
+    /// Event type that triggered the webhook (always `BatchQueued` today)
+    pub event_type: WebhookEventType,
+    /// Relayer that queued the batch
+    #[serde(rename = "relayerId")]
+    pub relayer_id: RelayerId,
+    /// Chain ID the batch was queued on
+    #[serde(rename = "chainId")]
+    pub chain_id: ChainId,
+    /// Shared identifier for every member of the batch
+    #[serde(rename = "batchId")]
+    pub batch_id: Uuid,
+    /// Every transaction that was queued as part of this batch, in submission order
+    pub transactions: Vec<WebhookTransactionData>,
+    /// Timestamp when the batch was queued
+    pub timestamp: DateTime<Utc>,
+    /// API version for payload compatibility
+    pub api_version: String,
+}
+
+impl WebhookBatchPayload {
+    pub fn batch_queued(
+        relayer_id: RelayerId,
+        chain_id: ChainId,
+        batch_id: Uuid,
+        transactions: &[Transaction],
+    ) -> Self {
+        Self {
+            event_type: WebhookEventType::BatchQueued,
+            relayer_id,
+            chain_id,
+            batch_id,
+            transactions: transactions.iter().map(WebhookTransactionData::from).collect(),
+            timestamp: Utc::now(),
+            api_version: "1.0".to_string(),
+        }
+    }
+
+    pub fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+}
+
 impl WebhookPayload {
     pub fn new(transaction: &Transaction, event_type: WebhookEventType) -> Self {
         Self {
@@ -309,6 +354,32 @@ impl WebhookPayload {
         )
     }
 
+    pub fn transaction_stuck(transaction: &Transaction) -> Self {
+        Self::new(transaction, WebhookEventType::TransactionStuck)
+    }
+
+    pub fn transaction_rescued(
+        rescued_transaction: &Transaction,
+        original_transaction: &Transaction,
+    ) -> Self {
+        Self::new_with_original(
+            rescued_transaction,
+            WebhookEventType::TransactionRescued,
+            original_transaction,
+        )
+    }
+
+    pub fn transaction_reorged(
+        rolled_back_transaction: &Transaction,
+        mined_transaction: &Transaction,
+    ) -> Self {
+        Self::new_with_original(
+            rolled_back_transaction,
+            WebhookEventType::TransactionReorged,
+            mined_transaction,
+        )
+    }
+
     pub fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
         serde_json::to_value(self)
     }
@@ -10,6 +10,7 @@ use crate::{
     },
 };
 use chrono::Utc;
+use rand::{thread_rng, Rng};
 use reqwest::{Client, Response};
 use serde_json::Value;
 use std::{
@@ -73,22 +74,45 @@ impl WebhookSender {
                     self.log_webhook_success(&delivery, status_code, &response_text, duration_ms)
                         .await;
                 } else {
+                    let retry_after_ms = Self::parse_retry_after_ms(&response);
+                    let is_rate_limited = response.status().as_u16() == 429;
                     let error_text = response.text().await.unwrap_or_default();
                     let error =
                         format!("Webhook returned error status: {} - {}", status_code, error_text);
-                    warn!(
-                        "Webhook {} failed to {} with status {}: {}",
-                        delivery.id, delivery.webhook_config.endpoint, status_code, error
-                    );
-                    self.handle_failed_attempt(
-                        &mut delivery,
-                        error.clone(),
-                        start_time,
-                        Some(status_code),
-                        Some(error_text),
-                        duration_ms,
-                    )
-                    .await;
+
+                    if is_rate_limited || retry_after_ms.is_some() {
+                        let retry_delay = retry_after_ms.unwrap_or_else(|| {
+                            self.calculate_retry_delay_with_jitter(delivery.attempts)
+                        });
+                        warn!(
+                            "Webhook {} to {} was rate limited (status {}), retrying in {}ms without counting it as an attempt: {}",
+                            delivery.id, delivery.webhook_config.endpoint, status_code, retry_delay, error
+                        );
+                        self.handle_soft_failure(
+                            &mut delivery,
+                            error,
+                            start_time,
+                            Some(status_code),
+                            Some(error_text),
+                            duration_ms,
+                            retry_delay,
+                        )
+                        .await;
+                    } else {
+                        warn!(
+                            "Webhook {} failed to {} with status {}: {}",
+                            delivery.id, delivery.webhook_config.endpoint, status_code, error
+                        );
+                        self.handle_failed_attempt(
+                            &mut delivery,
+                            error.clone(),
+                            start_time,
+                            Some(status_code),
+                            Some(error_text),
+                            duration_ms,
+                        )
+                        .await;
+                    }
                 }
             }
             Err(e) => {
@@ -145,7 +169,7 @@ impl WebhookSender {
         duration_ms: i64,
     ) {
         let next_retry_delay = if delivery.should_retry() {
-            let delay = self.calculate_retry_delay(delivery.attempts);
+            let delay = self.calculate_retry_delay_with_jitter(delivery.attempts);
             Some(delay)
         } else {
             None
@@ -196,6 +220,36 @@ impl WebhookSender {
         }
     }
 
+    /// Reschedules a soft failure (429 / `Retry-After`) without incrementing the attempt counter,
+    /// so a rate-limited receiver never burns through `max_retries` just because it asked us to
+    /// slow down.
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_soft_failure(
+        &self,
+        delivery: &mut WebhookDelivery,
+        error: String,
+        now: SystemTime,
+        http_status_code: Option<i32>,
+        response_body: Option<String>,
+        duration_ms: i64,
+        retry_delay_ms: u64,
+    ) {
+        delivery.mark_soft_failure(now, retry_delay_ms);
+
+        self.log_webhook_failure(delivery, &error, http_status_code, response_body, duration_ms, false)
+            .await;
+    }
+
+    /// Parses a `Retry-After` response header into a delay in milliseconds. Only the
+    /// delay-in-seconds form is supported (the HTTP-date form is rare in practice for webhook
+    /// receivers); returns `None` if the header is absent or unparseable, in which case the caller
+    /// falls back to its own backoff calculation.
+    fn parse_retry_after_ms(response: &Response) -> Option<u64> {
+        let value = response.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+        let seconds = value.parse::<u64>().ok()?;
+        Some(seconds * 1000)
+    }
+
     fn calculate_retry_delay(&self, attempt: u32) -> u64 {
         let delay = (self.config.initial_retry_delay_ms as f32)
             * self.config.retry_multiplier.powi(attempt as i32);
@@ -203,6 +257,15 @@ impl WebhookSender {
         (delay as u64).min(self.config.max_retry_delay_ms)
     }
 
+    /// Same as `calculate_retry_delay`, plus up to +/-20% jitter so a receiver that is struggling
+    /// under load doesn't get hit by every retrying delivery at the exact same moment.
+    fn calculate_retry_delay_with_jitter(&self, attempt: u32) -> u64 {
+        let base_delay = self.calculate_retry_delay(attempt);
+        let jitter_factor = thread_rng().gen_range(0.8..1.2);
+
+        ((base_delay as f32 * jitter_factor) as u64).min(self.config.max_retry_delay_ms)
+    }
+
     pub async fn send_multiple_webhooks(
         &self,
         deliveries: Vec<WebhookDelivery>,
@@ -30,6 +30,17 @@ pub enum WebhookEventType {
     TextSigned,
     /// Typed data (EIP-712) was signed
     TypedDataSigned,
+    /// A relayer's balance dropped below its configured minimum
+    LowBalance,
+    /// An ordered batch of transactions was queued together
+    BatchQueued,
+    /// A transaction's fee escalation hit its ceiling and is blocking the nonce it holds
+    TransactionStuck,
+    /// A stuck transaction was replaced with a no-op self-send so later nonces can drain
+    TransactionRescued,
+    /// A previously mined transaction's block was reorged off the canonical chain and the
+    /// transaction was rolled back to inmempool to await being mined again
+    TransactionReorged,
 }
 
 impl From<TransactionStatus> for WebhookEventType {
@@ -133,6 +144,14 @@ impl WebhookDelivery {
         }
     }
 
+    /// Reschedules a retry without counting it as an attempt. Used for soft failures - a 429 or a
+    /// response carrying `Retry-After` - where the receiver is explicitly asking to be retried
+    /// later rather than rejecting the event, so it shouldn't eat into `max_retries`.
+    pub fn mark_soft_failure(&mut self, now: SystemTime, retry_delay_ms: u64) {
+        self.last_attempt_at = Some(now);
+        self.next_retry_at = Some(now + std::time::Duration::from_millis(retry_delay_ms));
+    }
+
     pub fn mark_completed(&mut self) {
         self.completed = true;
         self.next_retry_at = None;
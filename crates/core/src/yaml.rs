@@ -8,9 +8,9 @@ use thiserror::Error;
 use tracing::error;
 
 use crate::gas::{
-    deserialize_gas_provider, BlockNativeGasProviderSetupConfig, CustomGasFeeEstimator,
-    EtherscanGasProviderSetupConfig, GasProvider, InfuraGasProviderSetupConfig,
-    TenderlyGasProviderSetupConfig,
+    deserialize_gas_provider, BlockNativeGasProviderSetupConfig, CompositeGasProviderSetupConfig,
+    CustomGasFeeEstimator, EtherscanGasProviderSetupConfig, FeeHistoryGasProviderSetupConfig,
+    GasProvider, InfuraGasProviderSetupConfig, TenderlyGasProviderSetupConfig,
 };
 use crate::network::{ChainId, Network};
 use crate::transaction::types::TransactionSpeed;
@@ -145,6 +145,19 @@ pub struct RateLimitConfig {
     pub fallback_to_relayer: bool,
 }
 
+/// Controls how long finished transactions stay in the hot `relayer.transaction` table before
+/// being moved into cold storage. Both knobs are independent and additive - a transaction is
+/// archived once either one says it should be.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionRetentionConfig {
+    /// Archive terminal transactions once they are older than this many days.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub archive_after_days: Option<u32>,
+    /// Archive terminal transactions beyond the most recent N per relayer, regardless of age.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub keep_last_per_relayer: Option<u32>,
+}
+
 impl AwsKmsSigningProviderConfig {
     pub fn validate(&self) -> Result<(), String> {
         if self.region.is_empty() {
@@ -445,6 +458,14 @@ fn default_max_gas_price_multiplier() -> u64 {
     4
 }
 
+fn default_per_relayer_max_inflight() -> usize {
+    1000
+}
+
+fn default_supports_eip1559() -> bool {
+    true
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NetworkSetupConfig {
     pub name: String,
@@ -470,12 +491,25 @@ pub struct NetworkSetupConfig {
     pub confirmations: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub enable_sending_blobs: Option<bool>,
+    /// Whether this chain implements the London fork (EIP-1559). Defaults to `true`;
+    /// set to `false` for chains that only understand legacy type-0 gas pricing so the
+    /// gas estimator and transaction builder emit a legacy transaction instead.
+    #[serde(default = "default_supports_eip1559")]
+    pub supports_eip1559: bool,
     #[serde(default)]
     pub gas_bump_blocks_every: GasBumpBlockConfig,
     #[serde(default = "default_max_gas_price_multiplier")]
     pub max_gas_price_multiplier: u64,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub allowed_random_relayers: Option<AllOrOneOrManyAddresses>,
+    /// Total pending + inmempool transactions a relayer on this network may hold at once before
+    /// an incoming transaction must outscore the weakest queued one to be admitted.
+    #[serde(default = "default_per_relayer_max_inflight")]
+    pub per_relayer_max_inflight: usize,
+    /// Hard ceiling on how many not-yet-sent (future) nonces a relayer on this network may queue
+    /// ahead of what it has broadcast. Unbounded if not set.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_future_nonces: Option<usize>,
 }
 
 impl From<NetworkSetupConfig> for Network {
@@ -496,6 +530,14 @@ pub struct GasProviders {
     pub tenderly: Option<TenderlyGasProviderSetupConfig>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub custom: Option<CustomGasFeeEstimator>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub fee_history: Option<FeeHistoryGasProviderSetupConfig>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub composite: Option<CompositeGasProviderSetupConfig>,
+    /// Seconds to cache a gas provider's response for before re-querying it, shared across
+    /// every provider configured above. `None` disables caching entirely.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cache_ttl_seconds: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -941,6 +983,8 @@ pub struct SetupConfig {
     pub webhooks: Option<Vec<WebhookConfig>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub rate_limits: Option<RateLimitConfig>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub transaction_retention: Option<TransactionRetentionConfig>,
 }
 
 fn substitute_env_variables(contents: &str) -> Result<String, regex::Error> {
@@ -8,6 +8,7 @@ use rrelayer_core::transaction::types::Transaction;
 use rrelayer_core::{
     common_types::{EvmAddress, PagingContext},
     relayer::types::RelayerId,
+    transaction::api::get_relayed_transaction_status::RelayedTransactionStatusResult,
     transaction::api::get_transaction_status::RelayTransactionStatusResult,
     transaction::api::send_transaction::{RelayTransactionRequest, SendTransactionResult},
     transaction::api::types::TransactionSpeed,
@@ -170,6 +171,26 @@ impl RelayerClient {
         Ok(status_result)
     }
 
+    /// Looks up the status of a relayed (forced-inclusion) transaction. Returns `Ok(None)` both
+    /// when the ID doesn't exist and when it exists but isn't marked `relayed`.
+    pub async fn get_relayed_transaction_status(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<Option<RelayedTransactionStatusResult>> {
+        info!("Getting relayed transaction status for: {}", transaction_id);
+
+        let result = self
+            .sdk
+            .transaction
+            .get_relayed_status(transaction_id)
+            .await
+            .context("Failed to get relayed transaction status")?;
+
+        info!("Relayed transaction status: {:?}", result);
+
+        Ok(result)
+    }
+
     pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Result<Transaction> {
         info!("Getting transaction status for: {}", transaction_id);
 
@@ -96,6 +96,11 @@ impl TestModule for TransactionTests {
                 "Transaction expired state validation",
                 |runner| Box::pin(runner.transaction_status_expired()),
             ),
+            TestDefinition::new(
+                "transaction_status_relayed_failed",
+                "Relayed (forced-inclusion) transaction failed status query",
+                |runner| Box::pin(runner.transaction_status_relayed_failed()),
+            ),
             TestDefinition::new(
                 "transaction_inflight_counts",
                 "Transaction inflight count operations",
@@ -0,0 +1,44 @@
+use crate::tests::test_runner::TestRunner;
+use rrelayer_core::transaction::api::{RelayTransactionRequest, TransactionSpeed};
+use rrelayer_core::transaction::types::TransactionData;
+use tracing::info;
+
+impl TestRunner {
+    /// run single with:
+    /// make run-test-debug TEST=transaction_status_relayed_failed
+    ///
+    /// This harness has no forced-inclusion trigger to actually land a `relayed` transaction, so
+    /// this only exercises the query surface: a normally-sent transaction must never be returned
+    /// by the relayed-status lookup, proving relayed and non-relayed transactions stay distinct.
+    pub async fn transaction_status_relayed_failed(&self) -> anyhow::Result<()> {
+        info!("Testing relayed-failed transaction status query...");
+
+        let relayer = self.create_and_fund_relayer("relayed-failed-status-relayer").await?;
+        info!("Created relayer: {:?}", relayer);
+
+        let tx_request = RelayTransactionRequest {
+            to: self.config.anvil_accounts[1],
+            value: alloy::primitives::utils::parse_ether("0.1")?.into(),
+            data: TransactionData::empty(),
+            speed: Some(TransactionSpeed::FAST),
+            external_id: Some("test-relayed-failed".to_string()),
+            blobs: None,
+        };
+
+        let send_result =
+            self.relayer_client.sdk.transaction.send(&relayer.id, &tx_request, None).await?;
+
+        let relayed_status =
+            self.relayer_client.get_relayed_transaction_status(&send_result.id).await?;
+
+        if relayed_status.is_some() {
+            return Err(anyhow::anyhow!(
+                "Normally-sent transaction should not be visible through the relayed-status lookup, but got: {:?}",
+                relayed_status
+            ));
+        }
+
+        info!("[SUCCESS] Non-relayed transaction is correctly excluded from the relayed-status lookup");
+        Ok(())
+    }
+}
@@ -1,14 +1,20 @@
 use crate::api::{http::HttpClient, types::ApiResult};
 use reqwest::header::{HeaderMap, HeaderValue};
 use rrelayer_core::network::ChainId;
-use rrelayer_core::transaction::api::{CancelTransactionResponse, RelayTransactionStatusResult};
-use rrelayer_core::transaction::api::{RelayTransactionRequest, SendTransactionResult};
+use rrelayer_core::transaction::api::{
+    CancelTransactionResponse, CreateScheduledTransactionRequest, CreateScheduledTransactionResult,
+    RelayTransactionStatusResult, RelayedTransactionStatusResult,
+};
+use rrelayer_core::transaction::api::{
+    RelayTransactionRequest, SendBatchTransactionsRequest, SendBatchTransactionsResult,
+    SendTransactionResult,
+};
 use rrelayer_core::transaction::queue_system::ReplaceTransactionResult;
 use rrelayer_core::{
     RATE_LIMIT_HEADER_NAME,
     common_types::{PagingContext, PagingResult},
     relayer::RelayerId,
-    transaction::types::{Transaction, TransactionId},
+    transaction::types::{ScheduledTransaction, ScheduledTransactionId, Transaction, TransactionId},
 };
 use std::sync::Arc;
 
@@ -36,6 +42,16 @@ impl TransactionApi {
             .await
     }
 
+    pub async fn get_archived(
+        &self,
+        relayer_id: &RelayerId,
+        paging: &PagingContext,
+    ) -> ApiResult<PagingResult<Transaction>> {
+        self.client
+            .get_with_query(&format!("transactions/relayers/{}/archived", relayer_id), Some(paging))
+            .await
+    }
+
     pub async fn send(
         &self,
         relayer_id: &RelayerId,
@@ -58,6 +74,33 @@ impl TransactionApi {
             .await
     }
 
+    /// Submits an ordered batch of transactions in a single request. Every member shares the
+    /// returned `batchId` and is assigned a contiguous nonce. A validation failure rejects the
+    /// whole batch; a later failure leaves the members queued before it in place, and the error
+    /// lists their ids.
+    pub async fn send_batch(
+        &self,
+        relayer_id: &RelayerId,
+        transactions: &[RelayTransactionRequest],
+        rate_limit_key: Option<String>,
+    ) -> ApiResult<SendBatchTransactionsResult> {
+        let mut headers = HeaderMap::new();
+        if let Some(rate_limit_key) = rate_limit_key.as_ref() {
+            headers.insert(
+                RATE_LIMIT_HEADER_NAME,
+                HeaderValue::from_str(rate_limit_key).expect("Invalid rate limit key"),
+            );
+        }
+        let request = SendBatchTransactionsRequest { transactions: transactions.to_vec() };
+        self.client
+            .post_with_headers(
+                &format!("transactions/relayers/{}/send/batch", relayer_id),
+                &request,
+                headers,
+            )
+            .await
+    }
+
     pub async fn send_random(
         &self,
         chain_id: &ChainId,
@@ -128,6 +171,13 @@ impl TransactionApi {
         self.client.get_or_none(&format!("transactions/status/{}", transaction_id)).await
     }
 
+    pub async fn get_relayed_status(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> ApiResult<Option<RelayedTransactionStatusResult>> {
+        self.client.get_or_none(&format!("transactions/relayed/status/{}", transaction_id)).await
+    }
+
     pub async fn get_inmempool_count(&self, relayer_id: &RelayerId) -> ApiResult<u32> {
         self.client.get(&format!("transactions/relayers/{}/inmempool/count", relayer_id)).await
     }
@@ -135,4 +185,28 @@ impl TransactionApi {
     pub async fn get_pending_count(&self, relayer_id: &RelayerId) -> ApiResult<u32> {
         self.client.get(&format!("transactions/relayers/{}/pending/count", relayer_id)).await
     }
+
+    pub async fn create_scheduled(
+        &self,
+        relayer_id: &RelayerId,
+        scheduled_transaction: &CreateScheduledTransactionRequest,
+    ) -> ApiResult<CreateScheduledTransactionResult> {
+        self.client
+            .post(&format!("transactions/relayers/{}/scheduled", relayer_id), scheduled_transaction)
+            .await
+    }
+
+    pub async fn get_scheduled(
+        &self,
+        relayer_id: &RelayerId,
+        paging: &PagingContext,
+    ) -> ApiResult<PagingResult<ScheduledTransaction>> {
+        self.client
+            .get_with_query(&format!("transactions/relayers/{}/scheduled", relayer_id), Some(paging))
+            .await
+    }
+
+    pub async fn cancel_scheduled(&self, id: &ScheduledTransactionId) -> ApiResult<()> {
+        self.client.put_status(&format!("transactions/scheduled/cancel/{}", id), &()).await
+    }
 }
@@ -1,6 +1,6 @@
 use rrelayerr_core::{
     authentication::types::JwtRole,
-    common_types::{EvmAddress, PagingQuery, PagingResult},
+    common_types::{CursorPagingContext, CursorPagingResult, EvmAddress},
     user::types::User,
 };
 use serde::Serialize;
@@ -16,9 +16,14 @@ impl UserApi {
         Self { client }
     }
 
-    /// Get all users with pagination
-    pub async fn get(&self, paging_context: &PagingQuery) -> ApiResult<PagingResult<User>> {
-        self.client.get_with_query("users", Some(paging_context)).await
+    /// Get users, walking the result set page by page via `CursorPagingContext`.
+    pub async fn get(
+        &self,
+        paging_context: &CursorPagingContext,
+    ) -> ApiResult<CursorPagingResult<User>> {
+        self.client
+            .get_with_query("users", Some(paging_context))
+            .await
     }
 
     /// Add a new user
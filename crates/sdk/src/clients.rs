@@ -9,7 +9,8 @@ use crate::{ApiSdkError, AuthenticationApi};
 use rrelayer_core::authentication::api::StatusResponse;
 use rrelayer_core::signing::{SignedTextHistory, SignedTypedDataHistory};
 use rrelayer_core::transaction::api::{
-    CancelTransactionResponse, RelayTransactionRequest, SendTransactionResult,
+    CancelTransactionResponse, CreateScheduledTransactionRequest, CreateScheduledTransactionResult,
+    RelayTransactionRequest, SendTransactionResult,
 };
 use rrelayer_core::transaction::types::{TransactionSpeed, TransactionStatus};
 use rrelayer_core::{
@@ -18,7 +19,7 @@ use rrelayer_core::{
     network::{ChainId, Network},
     relayer::{CreateRelayerResult, GetRelayerResult, Relayer, RelayerId},
     transaction::api::RelayTransactionStatusResult,
-    transaction::types::{Transaction, TransactionId},
+    transaction::types::{ScheduledTransaction, ScheduledTransactionId, Transaction, TransactionId},
 };
 use std::str::FromStr;
 
@@ -355,6 +356,13 @@ impl<'a> AdminRelayerClientTransactionApi<'a> {
         self.transaction_api.get_all(self.relayer_id, paging_context).await
     }
 
+    pub async fn get_archived(
+        &self,
+        paging_context: &PagingContext,
+    ) -> ApiResult<PagingResult<Transaction>> {
+        self.transaction_api.get_archived(self.relayer_id, paging_context).await
+    }
+
     pub async fn replace(
         &self,
         transaction_id: &TransactionId,
@@ -380,6 +388,24 @@ impl<'a> AdminRelayerClientTransactionApi<'a> {
         self.transaction_api.send(self.relayer_id, transaction, rate_limit_key).await
     }
 
+    pub async fn create_scheduled(
+        &self,
+        scheduled_transaction: &CreateScheduledTransactionRequest,
+    ) -> ApiResult<CreateScheduledTransactionResult> {
+        self.transaction_api.create_scheduled(self.relayer_id, scheduled_transaction).await
+    }
+
+    pub async fn get_scheduled(
+        &self,
+        paging_context: &PagingContext,
+    ) -> ApiResult<PagingResult<ScheduledTransaction>> {
+        self.transaction_api.get_scheduled(self.relayer_id, paging_context).await
+    }
+
+    pub async fn cancel_scheduled(&self, id: &ScheduledTransactionId) -> ApiResult<()> {
+        self.transaction_api.cancel_scheduled(id).await
+    }
+
     pub async fn wait_for_transaction_receipt_by_id(
         &self,
         transaction_id: &TransactionId,